@@ -10,8 +10,10 @@ import os
 
 from .dotenv import load as load_dotenv
 from .run import run, load_student, load_area
+from .equivalency import load_equivalencies
 from .ms import pretty_ms
 from .stringify_v3 import summarize
+from .trace import trace
 # from .stringify_csv import to_csv
 from .audit import EstimateMsg, ResultMsg, NoAuditsCompletedMsg, ProgressMsg, Arguments
 
@@ -25,6 +27,7 @@ def main(sys_args: Optional[List[str]] = None) -> int:  # noqa: C901
     parser = argparse.ArgumentParser()
     parser.add_argument("--area", dest="area_file", help="the area specification file")
     parser.add_argument("--student", dest="student_file", help="the student data file")
+    parser.add_argument("--equivalencies", dest="equivalencies_file", help="a YAML or CSV table of old course codes and their current equivalents")
     parser.add_argument("--loglevel", dest="loglevel", choices=("warn", "debug", "info", "critical"), default="info")
     parser.add_argument("--json", action='store_true', help="output results as json")
     parser.add_argument("--json-with-transcript", action='store_true', help="output results as json, including the course transcript")
@@ -44,8 +47,12 @@ def main(sys_args: Optional[List[str]] = None) -> int:  # noqa: C901
     parser.add_argument("--ranks", dest='show_ranks', action='store_const', const=True, default=True)
     parser.add_argument("--no-ranks", dest='show_ranks', action='store_const', const=False)
     parser.add_argument("--claimed", dest='claimed', action='store_true')
+    parser.add_argument("--trace", action='store_true', help="with --json, also output each rule's candidate courses and why they did or did not count")
     cli_args = parser.parse_args(sys_args)
 
+    if cli_args.trace and not (cli_args.json or cli_args.json_with_transcript):
+        parser.error("--trace requires --json or --json-with-transcript")
+
     loglevel = getattr(logging, cli_args.loglevel.upper())
     logformat = "%(asctime)s %(name)s %(levelname)s %(message)s"
     logging.basicConfig(level=loglevel, format=logformat)
@@ -66,15 +73,17 @@ def main(sys_args: Optional[List[str]] = None) -> int:  # noqa: C901
         audit_each=cli_args.audit_each,
         transcript_only=cli_args.transcript,
         estimate_only=cli_args.estimate,
+        trace=cli_args.trace,
     )
 
     student = load_student(cli_args.student_file)
     area_spec = load_area(cli_args.area_file)
+    equivalencies = load_equivalencies(cli_args.equivalencies_file) if cli_args.equivalencies_file else {}
 
     if not cli_args.quiet:
         print(f"auditing #{student['stnum']} against {cli_args.area_file}", file=sys.stderr)
 
-    for msg in run(args, student=student, area_spec=area_spec):
+    for msg in run(args, student=student, area_spec=area_spec, equivalencies=equivalencies):
         if isinstance(msg, NoAuditsCompletedMsg):
             logger.critical('no audits completed')
             return 2
@@ -105,6 +114,7 @@ def main(sys_args: Optional[List[str]] = None) -> int:  # noqa: C901
                     show_ranks=cli_args.show_ranks,
                     print_path=cli_args.print_path,
                     claimed=cli_args.claimed,
+                    with_trace=cli_args.trace,
                 ))
 
         else:
@@ -125,6 +135,7 @@ def result_str(
     show_ranks: bool,
     claimed: bool,
     print_path: Optional[List[str]] = None,
+    with_trace: bool = False,
 ) -> str:
     if gpa_only:
         return f"GPA: {msg.result.gpa()}"
@@ -138,6 +149,9 @@ def result_str(
     # if as_csv:
     #     return to_csv(dict_result, transcript=msg.transcript)
 
+    if with_trace:
+        dict_result = {**dict_result, 'trace': trace(msg.result)}
+
     if as_json:
         return json.dumps(dict_result)
 
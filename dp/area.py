@@ -135,7 +135,7 @@ class AreaOfStudy(Base):
             for course, paths in specification.get("multicountable", {}).items()
         }
 
-        allowed_keys = {'name', 'type', 'major', 'degree', 'code', 'emphases', 'result', 'requirements', 'limit', 'multicountable', 'credit', 'exceptions-migrations'}
+        allowed_keys = {'name', 'type', 'major', 'degree', 'code', 'emphases', 'result', 'requirements', 'limit', 'multicountable', 'credit', 'exceptions-migrations', 'equivalencies'}
         given_keys = set(specification.keys())
         assert given_keys.difference(allowed_keys) == set(), f"expected set {given_keys.difference(allowed_keys)} to be empty (at ['$'])"
 
@@ -164,7 +164,7 @@ class AreaOfStudy(Base):
             )),
         )
 
-    def solutions(self, *, student: Student, exceptions: List[RuleException]) -> Iterator['AreaSolution']:
+    def solutions(self, *, student: Student, exceptions: List[RuleException], trace: bool = False) -> Iterator['AreaSolution']:
         logger.debug("evaluating area.result")
 
         forced_clbids = set(e.clbid for e in exceptions if isinstance(e, InsertionException) and e.forced is True)
@@ -183,6 +183,7 @@ class AreaOfStudy(Base):
             exceptions=group_exceptions(exceptions),
             multicountable=self.multicountable,
             templates=student.templates_as_dict(),
+            trace=trace,
         )
 
         for i, _c in enumerate(student.courses):
@@ -331,6 +332,7 @@ class AreaSolution(AreaOfStudy):
             message=None,
             path=('$', '%Common Requirements'),
             is_audited=False,
+            audited_by=None,
             in_gpa=False,
             is_contract=False,
             overridden=False,
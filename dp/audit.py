@@ -22,6 +22,7 @@ class Arguments:
     stop_after: Optional[int] = None
     progress_every: int = 1_000
     audit_each: int = 1
+    trace: bool = False
 
 
 @attr.s(slots=True, kw_only=True, auto_attribs=True)
@@ -83,7 +84,7 @@ def audit(*, area: AreaOfStudy, student: Student, args: Optional[Arguments] = No
     if args.estimate_only:
         return
 
-    for sol in area.solutions(student=student, exceptions=exceptions or []):
+    for sol in area.solutions(student=student, exceptions=exceptions or [], trace=args.trace):
         if total_count == 0:
             # ignore startup time
             start = time.perf_counter()
@@ -3,6 +3,7 @@ from typing import Optional, Tuple, Dict, FrozenSet, Any, TYPE_CHECKING
 from decimal import Decimal
 
 from .bases import Base
+from ..data.course import wildcard_parts
 from ..data.course_enums import GradeOption
 from ..status import ResultStatus
 
@@ -40,11 +41,14 @@ class BaseCourseRule(Base):
 
     # state
     matched_course: Optional['CourseInstance'] = None
+    # only recorded when tracing: each course the rule looked at, and why it was set aside (None if it wasn't)
+    considered: Tuple[Tuple['CourseInstance', Optional[str]], ...] = tuple()
 
     def to_dict(self) -> Dict[str, Any]:
         return {
             **super().to_dict(),
             "course": self.course,
+            "wildcard": self.wildcard(),
             "clbid": self.clbid,
             "crsid": self.crsid,
             "ap": self.ap,
@@ -71,6 +75,14 @@ class BaseCourseRule(Base):
     def type(self) -> str:
         return "course"
 
+    def wildcard(self) -> Optional[Dict[str, Any]]:
+        parts = wildcard_parts(self.course) if self.course else None
+        if parts is None:
+            return None
+
+        subject, level = parts
+        return {"subject": subject, "level": level}
+
     def rank(self) -> Tuple[Decimal, Decimal]:
         status = self.status()
 
@@ -41,6 +41,8 @@ class BaseQueryRule(Base):
     failed_claims: Tuple[Claim, ...]
     load_potentials: bool
     excluded_clbids: FrozenSet[str] = frozenset()
+    # only recorded when tracing: each course the rule looked at, and why it was set aside (None if it wasn't)
+    considered: Tuple[Tuple[Clausable, Optional[str]], ...] = tuple()
 
     def to_dict(self) -> Dict[str, Any]:
         return {
@@ -1,4 +1,5 @@
 import attr
+import enum
 from typing import Optional, Dict, Any, List, Tuple, TYPE_CHECKING
 from decimal import Decimal
 
@@ -11,12 +12,20 @@ if TYPE_CHECKING:
     from ..data.course import CourseInstance  # noqa: F401
 
 
+@enum.unique
+class AuditedBy(enum.Enum):
+    Department = "department"
+    Registrar = "registrar"
+    MusicOffice = "music-office"
+
+
 @attr.s(cache_hash=True, slots=True, kw_only=True, frozen=True, auto_attribs=True)
 class BaseRequirementRule(Base):
     name: str
     message: Optional[str]
     result: Optional[Base]
     is_audited: bool
+    audited_by: Optional[AuditedBy]
     is_contract: bool
     in_gpa: bool
     disjoint: Optional[bool]
@@ -28,6 +37,7 @@ class BaseRequirementRule(Base):
             "message": self.message,
             "result": self.result.to_dict() if self.result is not None else None,
             "is_audited": self.is_audited,
+            "audited_by": self.audited_by.value if self.audited_by else None,
             "is_contract": self.is_contract,
             "is_disjoint": self.disjoint,
             "in_gpa": self.in_gpa,
@@ -12,12 +12,17 @@ class Claim:
     failed: bool
 
     def to_dict(self) -> Dict[str, Any]:
-        return {
+        data: Dict[str, Any] = {
             "crsid": self.course.crsid,
             "clbid": self.course.clbid,
             "claimed_by": self.claimed_by,
         }
 
+        if self.course.substituted_for:
+            data["substituted_for"] = self.course.substituted_for
+
+        return data
+
     def __repr__(self) -> str:
         return f"Claim(status={'fail' if self.failed else 'ok'}, course={self.course!r}, claimed_by={'/'.join(self.claimed_by)!r})"
 
@@ -53,6 +53,9 @@ class RequirementContext:
 
     templates: Mapping[str, Tuple[TemplateCourse, ...]] = attr.ib(factory=dict)
 
+    # when set, course and query rules record every course they considered, and why they set any aside
+    trace: bool = False
+
     def with_transcript(
         self,
         transcript: Iterable[CourseInstance],
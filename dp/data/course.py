@@ -1,7 +1,9 @@
-from typing import Optional, Tuple, Dict, Sequence, Any, Iterable, Callable, Union, cast, TYPE_CHECKING
+from typing import Optional, Tuple, Dict, Sequence, Any, Iterable, Callable, Union, Pattern, cast, TYPE_CHECKING
 import attr
 from decimal import Decimal, ROUND_DOWN
+import functools
 import logging
+import re
 
 from .clausable import Clausable, ClausableIdentifier
 from .course_enums import GradeCode, GradeOption, SubType, CourseType, TranscriptCode, CourseTypeSortOrder
@@ -51,6 +53,8 @@ class CourseInstance(Clausable):
 
     identity_: str
     is_chbi_: Optional[int]
+    # the student's original course code, when an equivalency has replaced it
+    substituted_for: Optional[str] = None
     hash_cache_: Optional[int] = None
 
     def to_identifier(self) -> ClausableIdentifier:
@@ -285,6 +289,69 @@ clause_application_lookup: Dict[str, Callable[[CourseInstance, 'Predicate'], boo
 }
 
 
+WILDCARD_NUMBER = re.compile(r'(\d*)([Xx*]+)')
+
+
+@functools.lru_cache(maxsize=None)
+def course_wildcard(course: str) -> Optional[Pattern[str]]:
+    """A pattern for course specs like "CHEM 2XX" or "MATH 3**", where each trailing X or * stands
+    for any one digit. Returns None for a plain course code."""
+
+    subject, _, number = course.rpartition(' ')
+    match = WILDCARD_NUMBER.fullmatch(number)
+    if not subject or not match:
+        return None
+
+    digits, wildcards = match.groups()
+    return re.compile(f"{re.escape(subject)} {digits}\\d{{{len(wildcards)}}}")
+
+
+def wildcard_parts(course: str) -> Optional[Tuple[str, Optional[int]]]:
+    """The subject and level of a wildcard course spec, as in ("CHEM", 200) for "CHEM 2XX"; the
+    level is None for specs like "CHEM XXX". Returns None for a plain course code."""
+
+    subject, _, number = course.rpartition(' ')
+    match = WILDCARD_NUMBER.fullmatch(number)
+    if not subject or not match:
+        return None
+
+    digits, _wildcards = match.groups()
+    if not digits:
+        return subject, None
+
+    return subject, int(digits.ljust(len(number), '0'))
+
+
+def describe_course(course: str) -> str:
+    """Puts a wildcard course spec into words, as in "any 200-level CHEM course"; plain course
+    codes are returned as-is."""
+
+    parts = wildcard_parts(course)
+    if parts is None:
+        return course
+
+    subject, level = parts
+    if level is None:
+        return f"any {subject} course"
+
+    return f"any {level}-level {subject} course"
+
+
+def chbi_number(course_identity: str) -> Optional[int]:
+    """Which CH/BI course this is, if any; they count as CHEM or BIO courses depending on the number."""
+
+    if course_identity == 'CH/BI 125':
+        return 125
+    elif course_identity == 'CH/BI 126':
+        return 126
+    elif course_identity == 'CH/BI 127':
+        return 127
+    elif course_identity == 'CH/BI 227':
+        return 227
+
+    return None
+
+
 def load_course(  # noqa: C901
     data: Union[Dict, CourseInstance],
     *,
@@ -386,15 +453,7 @@ def load_course(  # noqa: C901
         suffix = ""
 
     course_identity = f"{subject} {number}{suffix}"
-    is_chbi = None
-    if course_identity == 'CH/BI 125':
-        is_chbi = 125
-    elif course_identity == 'CH/BI 126':
-        is_chbi = 126
-    elif course_identity == 'CH/BI 127':
-        is_chbi = 127
-    elif course_identity == 'CH/BI 227':
-        is_chbi = 227
+    is_chbi = chbi_number(course_identity)
 
     yearterm = f"{year}{term}"
 
@@ -8,9 +8,10 @@ from ..constants import Constants
 from ..exception import CourseOverrideException
 
 from .area_enums import AreaStatus
-from .course import load_course, CourseInstance
+from .course import load_course, course_wildcard, CourseInstance
 from .course_enums import GradeOption, GradeCode, TranscriptCode, CourseType, SubType, SUB_TYPE_LOOKUP
 from .area_pointer import AreaPointer
+from ..equivalency import apply_equivalency
 from .music import MusicAttendance, MusicPerformance, MusicProficiencies, MusicMediums
 
 logger = logging.getLogger(__name__)
@@ -83,9 +84,12 @@ class Student:
         code: str = '000',
         overrides: Sequence[CourseOverrideException] = tuple(),
         credits_overrides: Optional[Dict[str, str]] = None,
+        equivalencies: Optional[Dict[str, str]] = None,
     ) -> 'Student':
         if not credits_overrides:
             credits_overrides = {}
+        if not equivalencies:
+            equivalencies = {}
 
         overrides = list(overrides)
 
@@ -96,7 +100,7 @@ class Student:
         current_term = data.get('current_term', None)
 
         data_courses = data.get('courses', [])
-        load_transcript_args = dict(current_term=current_term, overrides=overrides, credits_overrides=credits_overrides)
+        load_transcript_args = dict(current_term=current_term, overrides=overrides, credits_overrides=credits_overrides, equivalencies=equivalencies)
         courses = [c for c in load_transcript(data_courses, **load_transcript_args)]
         courses = sorted(courses, key=lambda c: c.sort_order())
 
@@ -295,6 +299,7 @@ def load_transcript(
     current_term: Optional[str] = None,
     overrides: List[CourseOverrideException],
     credits_overrides: Dict[str, str],
+    equivalencies: Optional[Dict[str, str]] = None,
 ) -> Iterator[CourseInstance]:
     skip_grades = {
         GradeCode._N,  # NoPass
@@ -313,6 +318,7 @@ def load_transcript(
 
     for row in courses:
         c = load_course(row, current_term=current_term, overrides=overrides, credits_overrides=credits_overrides)
+        c = apply_equivalency(c, equivalencies or {})
 
         if c.clbid in clbids:
             old_clbid = c.clbid
@@ -404,7 +410,11 @@ def _course_filter(c: CourseInstance, f: CourseFilterArgs) -> bool:  # noqa: C90
 
     # compare course identity
     if f.course is not None:
-        if c.identity_ != f.course:
+        wildcard = course_wildcard(f.course)
+        if wildcard is not None:
+            if not wildcard.fullmatch(c.identity_):
+                return False
+        elif c.identity_ != f.course:
             # print('failed on course identity')
             return False
 
@@ -0,0 +1,71 @@
+from typing import Dict, List, Union, Any
+import pathlib
+import logging
+import csv
+import re
+
+import attr
+import yaml
+
+from .data.course import CourseInstance, chbi_number
+
+logger = logging.getLogger(__name__)
+
+
+def parse_equivalencies(data: Union[Dict[str, str], List[Dict[str, Any]], None]) -> Dict[str, str]:
+    """Accepts either a mapping of old course codes to new ones, like
+    `{"CS 251": "CSCI 251"}`, or a list of `{from: "CS 251", to: "CSCI 251"}` items."""
+
+    if not data:
+        return {}
+
+    if isinstance(data, dict):
+        return {str(k).strip(): str(v).strip() for k, v in data.items()}
+
+    return {str(item['from']).strip(): str(item['to']).strip() for item in data}
+
+
+def load_equivalencies(filename: Union[str, pathlib.Path]) -> Dict[str, str]:
+    """Loads an equivalency table from a YAML file, or from a CSV file with "from" and "to" columns."""
+
+    path = pathlib.Path(filename)
+
+    with open(path, "r", encoding="utf-8") as infile:
+        if path.suffix == '.csv':
+            return parse_equivalencies([row for row in csv.DictReader(infile)])
+
+        return parse_equivalencies(yaml.load(stream=infile, Loader=yaml.SafeLoader))
+
+
+def apply_equivalency(course: CourseInstance, equivalencies: Dict[str, str]) -> CourseInstance:
+    if not equivalencies:
+        return course
+
+    original = f"{course.subject} {course.number}"
+    replacement = equivalencies.get(original, None)
+    if replacement is None:
+        return course
+
+    subject, _, number = replacement.rpartition(' ')
+    assert subject and number, f"expected the equivalency for {original!r} to be a course code, like 'CSCI 251', not {replacement!r}"
+
+    # keep the lab/discussion suffix, if any
+    suffix = course.identity_[len(original):]
+    identity = f"{subject} {number}{suffix}"
+
+    # the level follows the new number, as in "CS 151" => "CSCI 251"; a number without leading
+    # digits keeps the level that the transcript gave
+    digits = re.match(r'\d+', number)
+    level = int(digits.group(0)) // 100 * 100 if digits else course.level
+
+    logger.debug('treating %s as %s, due to an equivalency', course, replacement)
+
+    return attr.evolve(
+        course,
+        subject=subject,
+        number=number,
+        level=level,
+        identity_=identity,
+        is_chbi_=chbi_number(identity),
+        substituted_for=original,
+    )
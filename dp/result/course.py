@@ -38,6 +38,7 @@ class CourseResult(Result, BaseCourseRule):
             term=solution.term,
             section=solution.section,
             sub_type=solution.sub_type,
+            considered=solution.considered,
         )
 
     def claims(self) -> List[Claim]:
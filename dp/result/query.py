@@ -36,6 +36,7 @@ class QueryResult(Result, BaseQueryRule):
             output=solution.output,
             excluded_clbids=solution.excluded_clbids,
             load_potentials=solution.load_potentials,
+            considered=solution.considered,
         )
 
     def only_failed_claims(self) -> Sequence[Claim]:
@@ -20,6 +20,7 @@ class RequirementResult(Result, BaseRequirementRule):
             name=solution.name,
             message=solution.message,
             is_audited=solution.is_audited,
+            audited_by=solution.audited_by,
             is_contract=solution.is_contract,
             path=solution.path,
             disjoint=solution.disjoint,
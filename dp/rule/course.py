@@ -1,5 +1,5 @@
 import attr
-from typing import Dict, List, Iterator, Collection, Optional, TYPE_CHECKING
+from typing import Dict, List, Iterator, Collection, Optional, Tuple, TYPE_CHECKING
 import logging
 
 from ..base import Rule, BaseCourseRule
@@ -140,21 +140,19 @@ class CourseRule(Rule, BaseCourseRule):
 
         logger.debug('reference to %r [at %s]', self.identifier(), self.path)
 
-        did_yield = False
+        considered: List[Tuple['CourseInstance', Optional[str]]] = []
+        inserted: List[Tuple['CourseInstance', bool]] = []
+        matched: List['CourseInstance'] = []
 
         for insert in ctx.get_insert_exceptions(self.path):
             matched_course = ctx.forced_course_by_clbid(insert.clbid, path=self.path)
 
             if matched_course.clbid in self.excluded_clbids:
+                if ctx.trace: considered.append((matched_course, 'excluded'))
                 continue
 
-            did_yield = True
-            if insert.forced:
-                logger.debug('force-inserting %r into %s due to override', matched_course, self.path)
-                yield CourseSolution.from_rule(rule=self, course=matched_course, was_inserted=True, was_forced=True)
-            else:
-                logger.debug('inserting %r into %s due to override', matched_course, self.path)
-                yield CourseSolution.from_rule(rule=self, course=matched_course, was_inserted=True, was_forced=False)
+            if ctx.trace: considered.append((matched_course, None))
+            inserted.append((matched_course, insert.forced))
 
         # we ignore from_claimed here, because we check it again in
         # CourseSolution.audit; we cannot check it here because we don't
@@ -162,23 +160,36 @@ class CourseRule(Rule, BaseCourseRule):
         # list is always empty.
         for matched_course in ctx.find_courses(rule=self):
             if matched_course.clbid in self.excluded_clbids:
+                if ctx.trace: considered.append((matched_course, 'excluded'))
                 continue
 
             if self.grade is not None and matched_course.is_in_progress is False and matched_course.grade_points < self.grade:
                 logger.debug('course matching %r exists, but the grade of %s is below the allowed minimum grade of %s [at %s]', self.identifier(), matched_course.grade_points, self.grade, self.path)
+                if ctx.trace: considered.append((matched_course, 'grade'))
                 continue
 
             if self.grade_option is not None and matched_course.grade_option != self.grade_option:
                 logger.debug('course matching %r exists, but the course was taken %s, and the area requires that it be taken %s [at %s]', self.identifier(), matched_course.grade_option, self.grade_option, self.path)
+                if ctx.trace: considered.append((matched_course, 'grade-option'))
                 continue
 
-            did_yield = True
+            if ctx.trace: considered.append((matched_course, None))
+            matched.append(matched_course)
+
+        for matched_course, forced in inserted:
+            if forced:
+                logger.debug('force-inserting %r into %s due to override', matched_course, self.path)
+            else:
+                logger.debug('inserting %r into %s due to override', matched_course, self.path)
+            yield CourseSolution.from_rule(rule=self, course=matched_course, was_inserted=True, was_forced=forced, considered=tuple(considered))
+
+        for matched_course in matched:
             logger.debug('course matching %r exists [%r], and was generated as a possible solution [at %s]', self.identifier(), matched_course, self.path)
-            yield CourseSolution.from_rule(rule=self, course=matched_course)
+            yield CourseSolution.from_rule(rule=self, course=matched_course, considered=tuple(considered))
 
-        if not did_yield:
+        if not inserted and not matched:
             logger.debug('no possibilities for course %r was not found [at %s]', self.identifier(), self.path)
-            yield CourseSolution.from_rule(rule=self, course=None)
+            yield CourseSolution.from_rule(rule=self, course=None, considered=tuple(considered))
 
     def estimate(self, *, ctx: 'RequirementContext', depth: Optional[int] = None) -> int:
         return 1
@@ -125,13 +125,15 @@ class QueryRule(Rule, BaseQueryRule):
     def get_required_courses(self, *, ctx: 'RequirementContext') -> Collection['CourseInstance']:
         return tuple()
 
-    def get_data(self, *, ctx: 'RequirementContext') -> Iterable[Clausable]:
+    def get_data(self, *, ctx: 'RequirementContext', rejected: Optional[Dict[str, str]] = None) -> Iterable[Clausable]:
         if self.source is QuerySource.Courses:
             all_courses = ctx.transcript()
             if self.include_failed:
                 all_courses = ctx.transcript_with_failed_
 
             if self.excluded_clbids:
+                if rejected is not None:
+                    rejected.update((c.clbid, 'excluded') for c in all_courses if c.clbid in self.excluded_clbids)
                 return (c for c in all_courses if c.clbid not in self.excluded_clbids)
             else:
                 return all_courses
@@ -151,11 +153,22 @@ class QueryRule(Rule, BaseQueryRule):
         else:
             raise TypeError(f'unknown type of data for query, {self.source}')
 
-    def get_filtered_data(self, *, ctx: 'RequirementContext') -> Tuple[List[Clausable], Tuple[str, ...], Tuple[str, ...]]:
-        if self.where is not None:
+    def get_filtered_data(self, *, ctx: 'RequirementContext', rejected: Optional[Dict[str, str]] = None) -> Tuple[List[Clausable], Tuple[str, ...], Tuple[str, ...]]:
+        """
+        When given a `rejected` dict, fills it with the clbid of each course
+        that was set aside, and why.
+        """
+        if self.where is None:
+            data = list(self.get_data(ctx=ctx, rejected=rejected))
+        elif rejected is None:
             data = [item for item in self.get_data(ctx=ctx) if self.where.apply(item)]
         else:
-            data = list(self.get_data(ctx=ctx))
+            data = []
+            for item in self.get_data(ctx=ctx, rejected=rejected):
+                if self.where.apply(item):
+                    data.append(item)
+                else:
+                    rejected[cast(CourseInstance, item).clbid] = 'filter-miss'
 
         inserted_clbids: Tuple[str, ...] = tuple()
         force_inserted_clbids: Tuple[str, ...] = tuple()
@@ -165,6 +178,8 @@ class QueryRule(Rule, BaseQueryRule):
                     continue
 
                 inserted_clbids = (*inserted_clbids, insert.clbid)
+                if rejected is not None:
+                    rejected.pop(insert.clbid, None)
                 if insert.forced:
                     force_inserted_clbids = (*force_inserted_clbids, insert.clbid)
                     matched_course = ctx.forced_course_by_clbid(insert.clbid, path=self.path)
@@ -182,7 +197,8 @@ class QueryRule(Rule, BaseQueryRule):
             yield QuerySolution.from_rule(rule=self, output=tuple())
             return
 
-        data, inserted_clbids, force_inserted_clbids = self.get_filtered_data(ctx=ctx)
+        rejected: Optional[Dict[str, str]] = {} if ctx.trace and self.source is QuerySource.Courses else None
+        data, inserted_clbids, force_inserted_clbids = self.get_filtered_data(ctx=ctx, rejected=rejected)
         did_iter = False
 
         if self.source is QuerySource.Claimed:
@@ -201,9 +217,11 @@ class QueryRule(Rule, BaseQueryRule):
             has_inserted_clbids = bool(all_unique_inserted_clbids)
 
             for item_set in self.limit.limited_transcripts(courses, forced_clbids=force_inserted_clbids):
+                considered = self.considered_courses(ctx=ctx, rejected=rejected, item_set=item_set) if rejected is not None else tuple()
+
                 if self.attempt_claims is False:
                     did_iter = True
-                    yield QuerySolution.from_rule(rule=self, output=item_set, inserted=inserted_clbids, force_inserted=force_inserted_clbids)
+                    yield QuerySolution.from_rule(rule=self, output=item_set, inserted=inserted_clbids, force_inserted=force_inserted_clbids, considered=considered)
                     continue
 
                 for item_combo in iterate_item_set(item_set, rule=self):
@@ -220,7 +238,7 @@ class QueryRule(Rule, BaseQueryRule):
                         course_combo = tuple([*course_combo, *courses_for_insertion])
 
                     did_iter = True
-                    yield QuerySolution.from_rule(rule=self, output=course_combo, inserted=inserted_clbids, force_inserted=force_inserted_clbids)
+                    yield QuerySolution.from_rule(rule=self, output=course_combo, inserted=inserted_clbids, force_inserted=force_inserted_clbids, considered=considered)
 
         else:
             for item_combo in iterate_item_set(data, rule=self):
@@ -230,7 +248,28 @@ class QueryRule(Rule, BaseQueryRule):
         if not did_iter:
             # be sure we always yield something
             logger.debug("%s did not yield anything; yielding empty collection", self.path)
-            yield QuerySolution.from_rule(rule=self, output=tuple(), inserted=inserted_clbids, force_inserted=force_inserted_clbids)
+            considered = self.considered_courses(ctx=ctx, rejected=rejected, item_set=cast(List[CourseInstance], data)) if rejected is not None else tuple()
+            yield QuerySolution.from_rule(rule=self, output=tuple(), inserted=inserted_clbids, force_inserted=force_inserted_clbids, considered=considered)
+
+    def considered_courses(self, *, ctx: 'RequirementContext', rejected: Dict[str, str], item_set: Sequence[CourseInstance]) -> Tuple[Tuple[Clausable, Optional[str]], ...]:
+        """
+        Pairs every course this rule could have looked at with the reason it
+        was set aside, if any: whatever the filters recorded in `rejected`,
+        or "limit" if the rule's limits left it out of this item set.
+        """
+        pool = ctx.transcript_with_failed_ if self.include_failed else ctx.transcript()
+        selected = {c.clbid for c in item_set}
+        seen = {c.clbid for c in pool}
+
+        considered: List[Tuple[Clausable, Optional[str]]] = [
+            (c, rejected.get(c.clbid, None if c.clbid in selected else 'limit'))
+            for c in pool
+        ]
+
+        # force-inserted courses may come from outside of the transcript
+        considered.extend((c, None) for c in item_set if c.clbid not in seen)
+
+        return tuple(considered)
 
     def estimate(self, *, ctx: 'RequirementContext', depth: Optional[int] = None) -> int:
         if ctx.get_waive_exception(self.path):
@@ -3,6 +3,7 @@ import logging
 import attr
 
 from ..base import Rule, BaseRequirementRule
+from ..base.requirement import AuditedBy
 from ..constants import Constants
 from ..solution.requirement import RequirementSolution
 from ..exception import BlockException
@@ -37,6 +38,8 @@ class RequirementRule(Rule, BaseRequirementRule):
             'in_gpa', 'name', 'result', 'disjoint',
             'message', 'contract', 'requirements',
             'department_audited', 'department-audited',
+            'registrar_audited', 'registrar-audited',
+            'audited_by', 'audited-by',
         }
         given_keys = set(data.keys())
         assert given_keys.difference(allowed_keys) == set(), f"expected set {given_keys.difference(allowed_keys)} to be empty (at {path})"
@@ -51,7 +54,15 @@ class RequirementRule(Rule, BaseRequirementRule):
             unused_child_names = all_child_names.difference(used_child_names)
             assert unused_child_names == set(), f"expected {unused_child_names} to be empty"
 
-        is_audited = data.get("department_audited", data.get("department-audited", False))
+        audited_by: Optional[AuditedBy] = None
+        if data.get("audited_by", data.get("audited-by", None)) is not None:
+            audited_by = AuditedBy(data.get("audited_by", data.get("audited-by")))
+        elif data.get("department_audited", data.get("department-audited", False)):
+            audited_by = AuditedBy.Department
+        elif data.get("registrar_audited", data.get("registrar-audited", False)):
+            audited_by = AuditedBy.Registrar
+
+        is_audited = audited_by is not None
 
         if 'audit' in data:
             raise TypeError('you probably meant to indent that audit: key into the result: key')
@@ -76,6 +87,7 @@ class RequirementRule(Rule, BaseRequirementRule):
             disjoint=data.get("disjoint", None),
             in_gpa=data.get("in_gpa", True),
             is_audited=is_audited,
+            audited_by=audited_by,
             path=tuple(path),
             overridden=False,
         )
@@ -12,15 +12,19 @@ from .exception import load_exception, CourseOverrideException, load_migrations
 from .lib import grade_point_average_items, grade_point_average
 from .data.student import Student
 from .audit import audit, Message, Arguments
+from .equivalency import parse_equivalencies
 
 logger = logging.getLogger(__name__)
 
 
-def run(args: Arguments, *, student: Dict, area_spec: Dict) -> Iterator[Message]:
+def run(args: Arguments, *, student: Dict, area_spec: Dict, equivalencies: Optional[Dict[str, str]] = None) -> Iterator[Message]:
     area_code = area_spec['code']
 
     credit_assignments = area_spec.get('credit', {})
 
+    # equivalencies given in the area spec take precedence over any passed in from a table
+    equivalencies = {**(equivalencies or {}), **parse_equivalencies(area_spec.get('equivalencies', None))}
+
     exceptions_migrations = load_migrations(area_spec.get('exceptions-migrations', []))
 
     exceptions = [
@@ -30,7 +34,7 @@ def run(args: Arguments, *, student: Dict, area_spec: Dict) -> Iterator[Message]
     ]
     course_overrides = [e for e in exceptions if isinstance(e, CourseOverrideException)]
 
-    loaded = Student.load(student, code=area_code, overrides=course_overrides, credits_overrides=credit_assignments)
+    loaded = Student.load(student, code=area_code, overrides=course_overrides, credits_overrides=credit_assignments, equivalencies=equivalencies)
 
     if args.transcript_only:
         writer = csv.writer(sys.stdout)
@@ -1,5 +1,5 @@
 import attr
-from typing import List, Optional, TYPE_CHECKING
+from typing import List, Optional, Tuple, TYPE_CHECKING
 import logging
 
 from ..base import Solution, BaseCourseRule
@@ -15,7 +15,7 @@ logger = logging.getLogger(__name__)
 @attr.s(cache_hash=True, slots=True, kw_only=True, frozen=True, auto_attribs=True)
 class CourseSolution(Solution, BaseCourseRule):
     @staticmethod
-    def from_rule(*, rule: BaseCourseRule, course: Optional['CourseInstance'], was_inserted: bool = False, was_forced: bool = False, overridden: bool = False) -> 'CourseSolution':
+    def from_rule(*, rule: BaseCourseRule, course: Optional['CourseInstance'], was_inserted: bool = False, was_forced: bool = False, overridden: bool = False, considered: Tuple[Tuple['CourseInstance', Optional[str]], ...] = tuple()) -> 'CourseSolution':
         return CourseSolution(
             course=rule.course,
             clbid=rule.clbid,
@@ -37,6 +37,7 @@ class CourseSolution(Solution, BaseCourseRule):
             section=rule.section,
             sub_type=rule.sub_type,
             matched_course=course,
+            considered=considered,
         )
 
     def audit(self, *, ctx: 'RequirementContext') -> CourseResult:
@@ -32,6 +32,7 @@ class QuerySolution(Solution, BaseQueryRule):
         output: Tuple[Clausable, ...],
         inserted: Tuple[str, ...] = tuple(),
         force_inserted: Tuple[str, ...] = tuple(),
+        considered: Tuple[Tuple[Clausable, Optional[str]], ...] = tuple(),
     ) -> 'QuerySolution':
         return QuerySolution(
             source=rule.source,
@@ -52,6 +53,7 @@ class QuerySolution(Solution, BaseQueryRule):
             include_failed=rule.include_failed,
             excluded_clbids=rule.excluded_clbids,
             load_potentials=rule.load_potentials,
+            considered=considered,
         )
 
     def audit(self, *, ctx: 'RequirementContext') -> QueryResult:
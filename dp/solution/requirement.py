@@ -23,6 +23,7 @@ class RequirementSolution(Solution, BaseRequirementRule):
             name=rule.name,
             message=rule.message,
             is_audited=rule.is_audited,
+            audited_by=rule.audited_by,
             is_contract=rule.is_contract,
             disjoint=rule.disjoint,
             path=rule.path,
@@ -1,5 +1,5 @@
 from typing import List, Iterator, Any, Dict, Sequence, Set, Optional
-from .data.course import CourseInstance
+from .data.course import CourseInstance, describe_course
 from .op import str_operator
 from .ms import pretty_ms
 from .status import PassingStatusValues, WAIVED_AND_DONE
@@ -188,7 +188,7 @@ def print_course(
 
     status = emojify_course(course, rule["status"])
 
-    display_course = rule['course']
+    display_course = describe_course(rule['course']) if rule['course'] else rule['course']
     if course:
         display_course = f"{course.course().strip()} {course.name!r}"
     elif not rule["course"] and rule["ap"] != "":
@@ -353,7 +353,10 @@ def print_requirement(
 
     yield f"{prefix}{emoji} Requirement({rule['name']}) [{rule['status']}]"
     if rule["is_audited"]:
-        yield f"{prefix}    is manually audited"
+        if rule.get("audited_by", "department") == "department":
+            yield f"{prefix}    is manually audited"
+        else:
+            yield f"{prefix}    is manually audited by the {rule['audited_by'].replace('-', ' ')}"
         return
 
     if rule["result"]:
@@ -0,0 +1,120 @@
+# Explains the courses behind each course and query rule of an audit: every
+# candidate course that the rule considered, whether it counted, and if not, why.
+#
+# The rules only record what they considered when the audit runs with
+# `Arguments(trace=True)`; this module turns those records, and the claims in
+# the result's context, into the trace for the solution that was picked.
+
+from typing import Dict, Any, List, Optional, Iterator, Set, Tuple, cast
+
+from .area import AreaResult
+from .base import Base, BaseRequirementRule, BaseCountRule, BaseConditionalRule
+from .base.query import QuerySource
+from .context import RequirementContext
+from .data.course import CourseInstance
+from .result.course import CourseResult
+from .result.query import QueryResult
+
+
+def trace(result: AreaResult) -> List[Dict[str, Any]]:
+    return [rule_trace for rule in walk(result.result) for rule_trace in trace_rule(rule, ctx=result.context)]
+
+
+def walk(rule: Optional[Base]) -> Iterator[Base]:
+    if rule is None:
+        return
+
+    yield rule
+
+    if isinstance(rule, BaseRequirementRule):
+        yield from walk(rule.result)
+    elif isinstance(rule, BaseCountRule):
+        for item in rule.items:
+            yield from walk(item)
+    elif isinstance(rule, BaseConditionalRule):
+        if rule.condition.result is True:
+            yield from walk(rule.when_true)
+        elif rule.condition.result is False:
+            yield from walk(rule.when_false)
+
+
+def trace_rule(rule: Base, *, ctx: RequirementContext) -> Iterator[Dict[str, Any]]:
+    if isinstance(rule, CourseResult):
+        yield {"path": list(rule.path), "type": "course", "candidates": trace_course_rule(rule, ctx=ctx)}
+    elif isinstance(rule, QueryResult) and rule.source is QuerySource.Courses:
+        yield {"path": list(rule.path), "type": "query", "candidates": trace_query_rule(rule, ctx=ctx)}
+
+
+def trace_course_rule(rule: CourseResult, *, ctx: RequirementContext) -> List[Dict[str, Any]]:
+    attempt = rule.claim_attempt
+    candidates = []
+
+    for course, reason in rule.considered:
+        if reason is not None:
+            candidates.append(rejected(course, reason))
+        elif attempt is not None and attempt.course.clbid == course.clbid:
+            if attempt.failed:
+                candidates.append(rejected(course, "already-claimed", claimed_by=prior_claimers(course, rule, ctx=ctx)))
+            else:
+                candidates.append(counted(course))
+        else:
+            candidates.append(rejected(course, "not-selected"))
+
+    return candidates
+
+
+def trace_query_rule(rule: QueryResult, *, ctx: RequirementContext) -> List[Dict[str, Any]]:
+    output = {c.clbid for c in rule.output}
+    succeeded = {claim.course.clbid for claim in rule.successful_claims}
+    failed = {claim.course.clbid for claim in rule.failed_claims}
+
+    candidates = []
+    seen: Set[str] = set()
+
+    for item, reason in rule.considered:
+        course = cast(CourseInstance, item)
+        seen.add(course.clbid)
+
+        if reason is not None:
+            candidates.append(rejected(course, reason))
+        elif course.clbid in succeeded or (course.clbid in output and course.clbid not in failed):
+            candidates.append(counted(course))
+        elif course.clbid in failed:
+            candidates.append(rejected(course, "already-claimed", claimed_by=prior_claimers(course, rule, ctx=ctx)))
+        else:
+            candidates.append(rejected(course, "not-selected"))
+
+    # the area's own limits dropped these before any rule saw the transcript
+    candidates.extend(rejected(course, "area-limit") for course in ctx.transcript_with_excluded_ if course.clbid not in seen)
+
+    return candidates
+
+
+def prior_claimers(course: CourseInstance, rule: Base, *, ctx: RequirementContext) -> List[List[str]]:
+    seen: Set[Tuple[str, ...]] = set()
+    claimers = []
+
+    for claim in ctx.claims.get(course.clbid, []):
+        if claim.failed or claim.claimed_by == rule.path or claim.claimed_by in seen:
+            continue
+        seen.add(claim.claimed_by)
+        claimers.append(list(claim.claimed_by))
+
+    return claimers
+
+
+def counted(course: CourseInstance) -> Dict[str, Any]:
+    return {**describe(course), "decision": "counted"}
+
+
+def rejected(course: CourseInstance, reason: str, *, claimed_by: Optional[List[List[str]]] = None) -> Dict[str, Any]:
+    entry = {**describe(course), "decision": "rejected", "reason": reason}
+
+    if claimed_by is not None:
+        entry["claimed_by"] = claimed_by
+
+    return entry
+
+
+def describe(course: CourseInstance) -> Dict[str, Any]:
+    return {"clbid": course.clbid, "course": course.course()}
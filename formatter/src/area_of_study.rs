@@ -1,8 +1,10 @@
 use crate::limit::Limit;
 use crate::path::Path;
+use crate::rule::requirement::Requirement;
 use crate::rule::{Rule, RuleStatus};
 use crate::student::Student;
 use crate::to_prose::{ProseOptions, ToProse};
+use crate::to_summary::percent;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -23,6 +25,24 @@ pub struct AreaOfStudy {
     pub status: RuleStatus,
 }
 
+impl AreaOfStudy {
+    /// The top-level requirements which describe the area's emphases
+    pub fn emphasis_requirements(&self) -> Vec<&Requirement> {
+        match self.result.as_ref() {
+            Rule::Count(count) => count
+                .items
+                .iter()
+                .filter_map(|item| match item.as_ref() {
+                    Rule::Requirement(req) if req.is_emphasis() => Some(req),
+                    _ => None,
+                })
+                .collect(),
+            Rule::Requirement(req) if req.is_emphasis() => vec![req],
+            _ => vec![],
+        }
+    }
+}
+
 impl ToProse for AreaOfStudy {
     fn to_prose(
         &self,
@@ -43,14 +63,42 @@ impl ToProse for AreaOfStudy {
 
         if !self.limit.is_empty() {
             write!(f, "{}", " ".repeat(indent * 4))?;
-            writeln!(f, "Subject to these limits:")?;
+            writeln!(f, "{}", options.messages.get("limit.heading"))?;
             for l in &self.limit {
-                write!(f, "{}", " ")?;
+                write!(f, " ")?;
                 l.to_prose(f, student, options, indent + 1)?;
                 writeln!(f)?;
             }
         };
 
+        if options.show_summary {
+            let mut unsatisfied = self
+                .unsatisfied_requirements()
+                .into_iter()
+                .map(|r| (percent(&r.rank, &r.max_rank), r))
+                .collect::<Vec<_>>();
+            unsatisfied.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+            if !unsatisfied.is_empty() {
+                write!(f, "{}", " ".repeat(indent * 4))?;
+                writeln!(f, "{}", options.messages.get("summary.heading"))?;
+                for (percent, r) in unsatisfied {
+                    write!(f, "{}", " ".repeat((indent + 1) * 4))?;
+                    match percent {
+                        Some(percent) => writeln!(
+                            f,
+                            "- {}",
+                            options
+                                .messages
+                                .format("summary.percent-complete", &[&r.name, &percent])
+                        )?,
+                        None => writeln!(f, "- {}", r.name)?,
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
         self.result.to_prose(f, student, options, indent)?;
 
         Ok(())
@@ -60,37 +108,34 @@ impl ToProse for AreaOfStudy {
 use crate::to_record::{Cell, Record, RecordOptions, ToRecord};
 impl ToRecord for AreaOfStudy {
     fn get_row(&self, student: &Student, options: &RecordOptions, is_waived: bool) -> Vec<Record> {
-        let mut row: Vec<Record> = vec![];
-
-        row.push(Record {
-            title: "student id".to_string(),
-            subtitle: None,
-            status: self.status,
-            content: vec![Cell::Text(student.stnum.clone())],
-        });
-
-        row.push(Record {
-            title: "name".to_string(),
-            subtitle: None,
-            status: self.status,
-            content: vec![Cell::Text(student.name_sort.clone())],
-        });
-
-        row.push(Record {
-            title: "classification".to_string(),
-            subtitle: None,
-            status: self.status,
-            content: vec![Cell::Text(student.classification.to_string())],
-        });
-
-        row.push(Record {
-            title: "class year".to_string(),
-            subtitle: None,
-            status: self.status,
-            content: vec![Cell::Text(student.class.clone())],
-        });
+        let mut row: Vec<Record> = vec![
+            Record {
+                title: "student id".to_string(),
+                subtitle: None,
+                status: self.status,
+                content: vec![Cell::Text(student.stnum.clone())],
+            },
+            Record {
+                title: "name".to_string(),
+                subtitle: None,
+                status: self.status,
+                content: vec![Cell::Text(student.name_sort.clone())],
+            },
+            Record {
+                title: "classification".to_string(),
+                subtitle: None,
+                status: self.status,
+                content: vec![Cell::Text(student.classification.to_string())],
+            },
+            Record {
+                title: "class year".to_string(),
+                subtitle: None,
+                status: self.status,
+                content: vec![Cell::Text(student.class.clone())],
+            },
+        ];
 
-        row.append(&mut self.result.get_row(student, options, is_waived));
+        row.extend(options.arrange(self.result.get_row(student, options, is_waived)));
 
         row
     }
@@ -8,7 +8,7 @@ use crate::student::Course;
 use crate::student::{ClassLabId, Student};
 use crate::to_prose::{ProseOptions, ToProse};
 use crate::to_record::{Cell, Record, RecordOptions, ToRecord};
-use rust_decimal;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::cmp::max;
 use std::collections::BTreeSet;
@@ -22,6 +22,8 @@ pub enum AssertionKey {
     CountDistinctCourses,
     #[serde(rename = "count(terms)")]
     CountTerms,
+    #[serde(rename = "count(years)")]
+    CountYears,
     #[serde(rename = "count(terms_from_most_common_course_by_name)")]
     CountTermsFromMostCommonCourseByName,
     #[serde(rename = "count(terms_from_most_common_course)")]
@@ -55,6 +57,7 @@ impl Display for AssertionKey {
             AssertionKey::CountCourses => "count/classes",
             AssertionKey::CountDistinctCourses => "count/distinct-courses",
             AssertionKey::CountTerms => "count/terms",
+            AssertionKey::CountYears => "count/years",
             AssertionKey::CountTermsFromMostCommonCourse => "count/terms-from-most-common-course",
             AssertionKey::CountTermsFromMostCommonCourseByName => {
                 "count/terms-from-most-common-course-by-name"
@@ -76,6 +79,7 @@ impl Display for AssertionKey {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum Assertion {
     #[serde(rename = "assertion")]
     Rule(AssertionRule),
@@ -100,11 +104,10 @@ impl ToRecord for Assertion {
 }
 
 impl Assertion {
-    #[allow(dead_code)]
     fn get_resolved_items(&self) -> String {
         match self {
             Assertion::Rule(r) => {
-                if let Some(_) = r.resolved {
+                if r.resolved.is_some() {
                     let mut items = r.resolved_items.clone();
                     items.sort();
                     items.join(", ")
@@ -138,6 +141,23 @@ impl Assertion {
         }
     }
 
+    /// The assertion that actually applied, after resolving any conditions
+    pub fn active_rule(&self) -> Option<&AssertionRule> {
+        match self {
+            Assertion::Rule(r) => Some(r),
+            Assertion::Conditional(r) => match r.condition.result() {
+                Some(true) => Some(&r.when_true),
+                Some(false) => r.when_false.as_ref(),
+                None => None,
+            },
+            Assertion::DynamicConditional(r) => Some(&r.when_true),
+        }
+    }
+
+    pub fn evidence<'a>(&self, student: &'a Student) -> Option<AssertionEvidence<'a>> {
+        self.active_rule().map(|r| r.evidence(student))
+    }
+
     pub fn is_course_or_credit(&self) -> bool {
         match self {
             Assertion::Rule(r) => r.is_course_or_credit(),
@@ -164,40 +184,53 @@ impl Assertion {
 }
 
 impl ToRecord for AssertionRule {
-    fn get_row(&self, student: &Student, _options: &RecordOptions, is_waived: bool) -> Vec<Record> {
+    fn get_row(&self, student: &Student, options: &RecordOptions, is_waived: bool) -> Vec<Record> {
         let mut row: Vec<Record> = vec![];
 
         let _is_waived = is_waived || self.status.is_waived();
 
-        let statement = match &self.key {
-            AssertionKey::CountAreas => "areas",
-            AssertionKey::CountCourses => "courses",
-            AssertionKey::CountDistinctCourses => "distinct courses",
-            AssertionKey::CountPerformances => "performances",
-            AssertionKey::CountRecitals => "recitals",
-            AssertionKey::CountSubjects => "subjects",
-            AssertionKey::CountTerms => "terms",
-            AssertionKey::CountTermsFromMostCommonCourse => "terms from the most common course",
+        let statement = options.messages.get(match &self.key {
+            AssertionKey::CountAreas => "assertion.noun.areas",
+            AssertionKey::CountCourses => "assertion.noun.courses",
+            AssertionKey::CountDistinctCourses => "assertion.noun.distinct-courses",
+            AssertionKey::CountPerformances => "assertion.noun.performances",
+            AssertionKey::CountRecitals => "assertion.noun.recitals",
+            AssertionKey::CountSubjects => "assertion.noun.subjects",
+            AssertionKey::CountTerms => "assertion.noun.terms",
+            AssertionKey::CountYears => "assertion.noun.years",
+            AssertionKey::CountTermsFromMostCommonCourse => {
+                "assertion.noun.terms-from-most-common-course"
+            }
             AssertionKey::CountTermsFromMostCommonCourseByName => {
-                "terms from the most common course, by name"
+                "assertion.noun.terms-from-most-common-course-by-name"
             }
-            AssertionKey::SumCredits => "credits",
-            AssertionKey::SumCreditsFromSingleSubject => "credits from a single subject",
-            AssertionKey::AverageGrades => "grade",
-            AssertionKey::CountReligionTraditions => "religious traditions",
-            AssertionKey::CountInternationalRelationsRegions => "regions",
-            AssertionKey::CountMathPerspectives => "perspectives",
-        };
+            AssertionKey::SumCredits => "assertion.noun.credits",
+            AssertionKey::SumCreditsFromSingleSubject => {
+                "assertion.noun.credits-from-single-subject"
+            }
+            AssertionKey::AverageGrades => "assertion.noun.grade",
+            AssertionKey::CountReligionTraditions => "assertion.noun.religious-traditions",
+            AssertionKey::CountInternationalRelationsRegions => "assertion.noun.regions",
+            AssertionKey::CountMathPerspectives => "assertion.noun.perspectives",
+        });
 
         let header = match self.operator {
-            Operator::EqualTo => format!("needs … {}", statement),
-            Operator::NotEqualTo => format!("not … {}", statement),
+            Operator::EqualTo => options.messages.format("assertion.needs", &[&statement]),
+            Operator::NotEqualTo => options.messages.format("assertion.not", &[&statement]),
             Operator::In => unimplemented!(),
             Operator::NotIn => unimplemented!(),
-            Operator::LessThan => format!("fewer than … {}", statement),
-            Operator::LessThanOrEqualTo => format!("at most … {}", statement),
-            Operator::GreaterThan => format!("more than … {}", statement),
-            Operator::GreaterThanOrEqualTo => format!("at least … {}", statement),
+            Operator::LessThan => options
+                .messages
+                .format("assertion.fewer-than", &[&statement]),
+            Operator::LessThanOrEqualTo => {
+                options.messages.format("assertion.at-most", &[&statement])
+            }
+            Operator::GreaterThan => options
+                .messages
+                .format("assertion.more-than", &[&statement]),
+            Operator::GreaterThanOrEqualTo => {
+                options.messages.format("assertion.at-least", &[&statement])
+            }
         };
 
         let sigil = if self.status.is_passing() {
@@ -205,17 +238,19 @@ impl ToRecord for AssertionRule {
         } else {
             "✗"
         };
-        let resolved = self.resolved.clone().unwrap_or("0".into());
+        let evidence = self.evidence(student);
+        let resolved = evidence.observed.clone();
         let remaining_v = match self.operator {
-            Operator::GreaterThanOrEqualTo => {
-                let expected = self.expected.parse::<rust_decimal::Decimal>().unwrap();
-                let resolved = resolved.parse::<rust_decimal::Decimal>().unwrap();
-                let remain = max(rust_decimal::Decimal::new(0, 0), expected - resolved);
-                format!(
+            Operator::GreaterThanOrEqualTo => match evidence.margin {
+                Some(margin) => format!(
                     "{} remaining<br/>(needs {}, has {})",
-                    remain, expected, resolved
-                )
-            }
+                    max(Decimal::new(0, 0), -margin),
+                    evidence.expected,
+                    evidence.observed
+                ),
+                // non-numeric values, like grades or term names, have no margin to count down
+                None => format!("needs {}, has {}", evidence.expected, evidence.observed),
+            },
             Operator::NotEqualTo
             | Operator::EqualTo
             | Operator::In
@@ -235,14 +270,13 @@ impl ToRecord for AssertionRule {
             .get_clbids()
             .iter()
             .map(|clbid| {
-                student.get_class_by_clbid(&clbid).expect(
-                    format!(
+                student.get_class_by_clbid(clbid).unwrap_or_else(|| {
+                    panic!(
                         "expected stnum({}) to have clbid({})",
                         &student.stnum,
                         clbid.clbid(),
                     )
-                    .as_str(),
-                )
+                })
             })
             .collect::<Vec<_>>();
 
@@ -290,6 +324,8 @@ impl ToRecord for AssertionRule {
                 status: self.status,
                 content: if done_courses.is_empty() {
                     vec![]
+                } else if options.mark_exceptions {
+                    vec![Cell::marked_courses(&done_courses, &self.inserted_clbids)]
                 } else {
                     vec![Cell::DoneCourses(
                         done_courses.into_iter().cloned().collect(),
@@ -303,6 +339,8 @@ impl ToRecord for AssertionRule {
                 status: self.status,
                 content: if ip_courses.is_empty() {
                     vec![]
+                } else if options.mark_exceptions {
+                    vec![Cell::marked_courses(&ip_courses, &self.inserted_clbids)]
                 } else {
                     vec![Cell::InProgressCourses(
                         ip_courses.into_iter().cloned().collect(),
@@ -327,6 +365,7 @@ impl AssertionRule {
             AssertionKey::CountCourses => self.expected.parse().unwrap(),
             AssertionKey::CountDistinctCourses => self.expected.parse().unwrap(),
             AssertionKey::CountTerms => self.expected.parse().unwrap(),
+            AssertionKey::CountYears => self.expected.parse().unwrap(),
             AssertionKey::CountTermsFromMostCommonCourse => self.expected.parse().unwrap(),
             AssertionKey::CountTermsFromMostCommonCourseByName => self.expected.parse().unwrap(),
             AssertionKey::CountPerformances => self.expected.parse().unwrap(),
@@ -372,6 +411,7 @@ impl AssertionRule {
             AssertionKey::SumCredits => true,
             AssertionKey::SumCreditsFromSingleSubject => false,
             AssertionKey::CountTerms => false,
+            AssertionKey::CountYears => false,
             AssertionKey::CountTermsFromMostCommonCourse => false,
             AssertionKey::CountTermsFromMostCommonCourseByName => false,
             AssertionKey::CountPerformances => false,
@@ -408,7 +448,7 @@ impl ToRecord for ConditionalAssertion {
             vec![]
         };
 
-        vec![if_true, if_false].iter().flatten().cloned().collect()
+        [if_true, if_false].iter().flatten().cloned().collect()
     }
 
     fn get_requirements(&self) -> Vec<String> {
@@ -534,7 +574,12 @@ impl ToProse for AssertionRule {
 
         write!(f, "{}", " ".repeat(indent * 4))?;
 
-        writeln!(f, "status: {:?}", self.status)?;
+        writeln!(
+            f,
+            "{}: {}",
+            options.messages.get("status.label"),
+            options.paint_status(&self.status)
+        )?;
 
         write!(f, "{}", " ".repeat(indent * 4))?;
 
@@ -562,6 +607,14 @@ impl ToProse for AssertionRule {
             write!(f, " [label: \"{}\"]", label)?;
         }
 
+        if let (Some(_), Some(margin)) = (&self.resolved, self.margin()) {
+            if margin.is_sign_negative() {
+                write!(f, " (margin: {})", margin)?;
+            } else {
+                write!(f, " (margin: +{})", margin)?;
+            }
+        }
+
         writeln!(f)?;
 
         if let Some(filter) = &self.filter {
@@ -573,11 +626,14 @@ impl ToProse for AssertionRule {
 
         let as_enum = Assertion::Rule(self.clone());
 
-        // let resolved_items = get_resolved_items(&as_enum);
-        // if !resolved_items.is_empty() {
-        //     write!(f, "{}", " ".repeat(indent * 4))?;
-        //     writeln!(f, "resolved items: {}", resolved_items)?;
-        // }
+        // performances, recitals, and areas have no clbids to show, so list what was counted instead
+        if !matches!(self.data_type, DataType::Course) {
+            let resolved_items = as_enum.get_resolved_items();
+            if !resolved_items.is_empty() {
+                write!(f, "{}", " ".repeat(indent * 4))?;
+                writeln!(f, "resolved items: {}", resolved_items)?;
+            }
+        }
 
         let resolved_clbids = as_enum.get_resolved_clbids();
         if !resolved_clbids.is_empty() {
@@ -593,7 +649,7 @@ impl ToProse for AssertionRule {
                 // };
                 let inserted_msg = "";
 
-                if let Some(course) = student.get_class_by_clbid(&clbid) {
+                if let Some(course) = student.get_class_by_clbid(clbid) {
                     writeln!(
                         f,
                         "{:0>2}. {}{} {}",
@@ -626,25 +682,44 @@ impl ToProse for ConditionalAssertion {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "If: [{}]", self.condition)?;
+        writeln!(
+            f,
+            "{}",
+            options
+                .messages
+                .format("conditional.if", &[&self.condition])
+        )?;
 
         let branch = self.condition.result();
         let true_branch = if branch == Some(true) { "t." } else { "" };
         let false_branch = if branch == Some(false) { "f!" } else { "" };
 
-        writeln!(f, "Then ({})", true_branch)?;
+        write!(f, "{}", " ".repeat(indent * 4))?;
+        writeln!(
+            f,
+            "{}",
+            options.messages.format("conditional.then", &[&true_branch])
+        )?;
 
         self.when_true.to_prose(f, student, options, indent + 1)?;
 
         writeln!(f)?;
 
-        writeln!(f, "Else ({})", false_branch)?;
+        write!(f, "{}", " ".repeat(indent * 4))?;
+        writeln!(
+            f,
+            "{}",
+            options
+                .messages
+                .format("conditional.else", &[&false_branch])
+        )?;
 
         if let Some(pred) = &self.when_false {
             pred.to_prose(f, student, options, indent + 1)?;
             writeln!(f)?;
         } else {
-            write!(f, "do nothing")?;
+            write!(f, "{}", " ".repeat((indent + 1) * 4))?;
+            writeln!(f, "{}", options.messages.get("conditional.do-nothing"))?;
         }
 
         Ok(())
@@ -665,26 +740,86 @@ impl ToProse for DynamicConditionalAssertion {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "If: [{}]", self.condition)?;
+        writeln!(
+            f,
+            "{}",
+            options
+                .messages
+                .format("conditional.if", &[&self.condition])
+        )?;
 
         let branch = self.condition.result();
         let true_branch = if branch == Some(true) { "t." } else { "" };
         let false_branch = if branch == Some(false) { "f!" } else { "" };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "Then ({})", true_branch)?;
+        writeln!(
+            f,
+            "{}",
+            options.messages.format("conditional.then", &[&true_branch])
+        )?;
 
         self.when_true.to_prose(f, student, options, indent)?;
 
         writeln!(f)?;
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "Else ({}): do nothing", false_branch)?;
+        writeln!(
+            f,
+            "{}: {}",
+            options
+                .messages
+                .format("conditional.else", &[&false_branch]),
+            options.messages.get("conditional.do-nothing")
+        )?;
 
         Ok(())
     }
 }
 
+/// What an assertion was checked against, and how far from the expected value it landed
+#[derive(Debug, Clone)]
+pub struct AssertionEvidence<'a> {
+    pub courses: Vec<&'a Course>,
+    pub observed: String,
+    pub expected: String,
+    pub margin: Option<Decimal>,
+}
+
+impl AssertionRule {
+    /// How far the observed value is from the expected value, for numeric comparisons: positive
+    /// when it is on the allowed side of the limit (above a minimum, below a maximum), and negative
+    /// when it falls short of a minimum or goes over a maximum
+    pub fn margin(&self) -> Option<Decimal> {
+        let observed = self.resolved.as_deref().unwrap_or("0").parse::<Decimal>();
+        let expected = self.expected.parse::<Decimal>();
+
+        let (observed, expected) = match (observed, expected) {
+            (Ok(observed), Ok(expected)) => (observed, expected),
+            _ => return None,
+        };
+
+        match self.operator {
+            Operator::GreaterThan | Operator::GreaterThanOrEqualTo => Some(observed - expected),
+            Operator::LessThan | Operator::LessThanOrEqualTo => Some(expected - observed),
+            Operator::EqualTo | Operator::NotEqualTo | Operator::In | Operator::NotIn => None,
+        }
+    }
+
+    pub fn evidence<'a>(&self, student: &'a Student) -> AssertionEvidence<'a> {
+        AssertionEvidence {
+            courses: self
+                .get_clbids()
+                .iter()
+                .filter_map(|clbid| student.get_class_by_clbid(clbid))
+                .collect(),
+            observed: self.resolved.clone().unwrap_or_else(|| "0".into()),
+            expected: self.expected.clone(),
+            margin: self.margin(),
+        }
+    }
+}
+
 // TODO: serde rule to convert string|number to a string; use for "original" key
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -7,4 +7,7 @@ pub struct Claim {
     pub claimed_by: Path,
     pub clbid: ClassLabId,
     pub crsid: CourseId,
+    /// The student's original course code, when an equivalency matched it to this rule
+    #[serde(default)]
+    pub substituted_for: Option<String>,
 }
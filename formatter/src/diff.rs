@@ -0,0 +1,181 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::path::Path;
+use crate::rule::{Rule, RuleStatus};
+use crate::student::ClassLabId;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+/// A single difference between two results for the same student and area
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum Change {
+    #[serde(rename = "rule-added")]
+    RuleAdded { path: Path },
+    #[serde(rename = "rule-removed")]
+    RuleRemoved { path: Path },
+    #[serde(rename = "status")]
+    Status {
+        path: Path,
+        before: RuleStatus,
+        after: RuleStatus,
+    },
+    #[serde(rename = "rank")]
+    Rank {
+        path: Path,
+        before: String,
+        after: String,
+    },
+    #[serde(rename = "claim-added")]
+    ClaimAdded { path: Path, clbid: ClassLabId },
+    #[serde(rename = "claim-removed")]
+    ClaimRemoved { path: Path, clbid: ClassLabId },
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::RuleAdded { path } => write!(f, "{}: added", path),
+            Change::RuleRemoved { path } => write!(f, "{}: removed", path),
+            Change::Status {
+                path,
+                before,
+                after,
+            } => write!(f, "{}: status {:?} → {:?}", path, before, after),
+            Change::Rank {
+                path,
+                before,
+                after,
+            } => write!(f, "{}: rank {} → {}", path, before, after),
+            Change::ClaimAdded { path, clbid } => {
+                write!(f, "{}: + claimed #{}", path, clbid.clbid())
+            }
+            Change::ClaimRemoved { path, clbid } => {
+                write!(f, "{}: - claimed #{}", path, clbid.clbid())
+            }
+        }
+    }
+}
+
+struct Node<'a> {
+    path: &'a Path,
+    status: &'a RuleStatus,
+    rank: &'a str,
+    claims: BTreeSet<ClassLabId>,
+}
+
+fn flatten<'a>(rule: &'a Rule, nodes: &mut BTreeMap<Vec<String>, Node<'a>>) {
+    let (path, status, rank, claims, children): (_, _, _, _, Vec<&Rule>) = match rule {
+        Rule::Count(r) => (
+            &r.path,
+            &r.status,
+            &r.rank,
+            BTreeSet::new(),
+            r.items.iter().map(|i| i.as_ref()).collect(),
+        ),
+        Rule::Requirement(r) => (
+            &r.path,
+            &r.status,
+            &r.rank,
+            BTreeSet::new(),
+            r.result.iter().map(|r| r.as_ref()).collect(),
+        ),
+        Rule::Conditional(r) => {
+            let mut children = vec![r.when_true.as_ref()];
+            children.extend(r.when_false.iter().map(|r| r.as_ref()));
+            (&r.path, &r.status, &r.rank, BTreeSet::new(), children)
+        }
+        Rule::Course(r) => (
+            &r.path,
+            &r.status,
+            &r.rank,
+            r.claims.iter().map(|c| c.clbid.clone()).collect(),
+            vec![],
+        ),
+        Rule::Proficiency(r) => (&r.path, &r.status, &r.rank, rule.claimed_clbids(), vec![]),
+        Rule::Query(r) => (
+            &r.path,
+            &r.status,
+            &r.rank,
+            r.claims.iter().map(|c| c.clbid.clone()).collect(),
+            vec![],
+        ),
+    };
+
+    nodes.insert(
+        path.0.clone(),
+        Node {
+            path,
+            status,
+            rank,
+            claims,
+        },
+    );
+
+    for child in children {
+        flatten(child, nodes);
+    }
+}
+
+/// Compares two results for the same student and area, rule by rule, matching rules by path
+pub fn diff(before: &AreaOfStudy, after: &AreaOfStudy) -> Vec<Change> {
+    let mut old = BTreeMap::new();
+    flatten(&before.result, &mut old);
+
+    let mut new = BTreeMap::new();
+    flatten(&after.result, &mut new);
+
+    let mut changes = Vec::new();
+
+    for (key, a) in &old {
+        let b = match new.get(key) {
+            Some(b) => b,
+            None => {
+                changes.push(Change::RuleRemoved {
+                    path: a.path.clone(),
+                });
+                continue;
+            }
+        };
+
+        if a.status != b.status {
+            changes.push(Change::Status {
+                path: a.path.clone(),
+                before: *a.status,
+                after: *b.status,
+            });
+        }
+
+        if a.rank != b.rank {
+            changes.push(Change::Rank {
+                path: a.path.clone(),
+                before: a.rank.to_string(),
+                after: b.rank.to_string(),
+            });
+        }
+
+        for clbid in a.claims.difference(&b.claims) {
+            changes.push(Change::ClaimRemoved {
+                path: a.path.clone(),
+                clbid: clbid.clone(),
+            });
+        }
+
+        for clbid in b.claims.difference(&a.claims) {
+            changes.push(Change::ClaimAdded {
+                path: a.path.clone(),
+                clbid: clbid.clone(),
+            });
+        }
+    }
+
+    for (key, b) in &new {
+        if !old.contains_key(key) {
+            changes.push(Change::RuleAdded {
+                path: b.path.clone(),
+            });
+        }
+    }
+
+    changes
+}
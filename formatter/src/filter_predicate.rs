@@ -11,6 +11,8 @@ pub enum CompoundPredicate {
     Or { predicates: Vec<CompoundPredicate> },
     #[serde(rename = "pred--and")]
     And { predicates: Vec<CompoundPredicate> },
+    #[serde(rename = "pred--not")]
+    Not { predicate: Box<CompoundPredicate> },
     #[serde(rename = "pred--if")]
     Conditional(ConditionalPredicate),
     #[serde(rename = "predicate")]
@@ -32,6 +34,10 @@ impl ToProse for CompoundPredicate {
             CompoundPredicate::And { predicates } => {
                 self.join_predicates(f, predicates, student, options, indent, "and ")
             }
+            CompoundPredicate::Not { predicate } => {
+                f.write_str("not ")?;
+                predicate.to_grouped_prose(f, student, options, indent)
+            }
             CompoundPredicate::Conditional(p) => p.to_prose(f, student, options, indent),
             CompoundPredicate::Predicate(p) => p.to_prose(f, student, options, indent),
         }
@@ -39,6 +45,23 @@ impl ToProse for CompoundPredicate {
 }
 
 impl CompoundPredicate {
+    fn to_grouped_prose(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        student: &Student,
+        options: &ProseOptions,
+        indent: usize,
+    ) -> std::fmt::Result {
+        match self {
+            CompoundPredicate::Or { .. } | CompoundPredicate::And { .. } => {
+                f.write_str("(")?;
+                self.to_prose(f, student, options, indent)?;
+                f.write_str(")")
+            }
+            _ => self.to_prose(f, student, options, indent),
+        }
+    }
+
     fn join_predicates(
         &self,
         f: &mut std::fmt::Formatter<'_>,
@@ -51,7 +74,7 @@ impl CompoundPredicate {
         let indent_str = " ".repeat(indent * 4);
 
         for (i, p) in predicates.iter().enumerate() {
-            p.to_prose(f, student, options, indent)?;
+            p.to_grouped_prose(f, student, options, indent)?;
 
             if i != predicates.len() - 1 {
                 writeln!(f)?;
@@ -83,6 +106,7 @@ impl ToProse for Predicate {
     ) -> std::fmt::Result {
         let key = match self.key.as_str() {
             "attributes" => "bucket",
+            "gereqs" => "GE",
             "is_in_progress" => "in-progress",
             "is_stolaf" => "from STOLAF",
             _ => self.key.as_str(),
@@ -96,6 +120,10 @@ impl ToProse for Predicate {
             write!(f, "{}", key)?;
         } else if self.operator == Operator::EqualTo && expected == serde_json::Value::Bool(false) {
             write!(f, "not {}", key)?;
+        } else if self.is_list_valued() && self.operator == Operator::In {
+            write!(f, "{} any of {}", key, expected)?;
+        } else if self.is_list_valued() && self.operator == Operator::NotIn {
+            write!(f, "{} none of {}", key, expected)?;
         } else {
             write!(f, "{} {} {}", key, op, expected)?;
         }
@@ -112,6 +140,12 @@ impl ToProse for Predicate {
     }
 }
 
+impl Predicate {
+    fn is_list_valued(&self) -> bool {
+        matches!(self.key.as_str(), "attributes" | "gereqs")
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ConditionalPredicate {
     pub condition: PredicateExpression<StaticPredicateConditionFunction>,
@@ -127,21 +161,23 @@ impl ToProse for ConditionalPredicate {
         options: &ProseOptions,
         indent: usize,
     ) -> std::fmt::Result {
-        write!(f, "If: [{}] ", self.condition)?;
+        let m = &options.messages;
+
+        write!(f, "{} ", m.format("conditional.if", &[&self.condition]))?;
 
         let branch = self.condition.result;
         let true_branch = if branch == Some(true) { "t." } else { "" };
         let false_branch = if branch == Some(false) { "f!" } else { "" };
 
-        write!(f, "Then ({}): [", true_branch)?;
+        write!(f, "{}: [", m.format("conditional.then", &[&true_branch]))?;
         self.when_true.to_prose(f, student, options, indent)?;
         write!(f, "] ")?;
 
-        write!(f, "Else ({}): [", false_branch)?;
+        write!(f, "{}: [", m.format("conditional.else", &[&false_branch]))?;
         if let Some(pred) = &self.when_false {
             pred.to_prose(f, student, options, indent)?;
         } else {
-            f.write_str("do nothing")?;
+            f.write_str(m.get("conditional.do-nothing"))?;
         }
         write!(f, "]")?;
 
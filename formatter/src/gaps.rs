@@ -0,0 +1,217 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::assertion::AssertionRule;
+use crate::messages::Catalog;
+use crate::operator::Operator;
+use crate::path::Path;
+use crate::rule::{Rule, RuleStatus};
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+/// Something the student still needs in order to finish a failing rule
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum Gap {
+    /// A specific course that nothing has claimed yet
+    #[serde(rename = "course")]
+    Course { path: Path, course: String },
+    /// A proficiency that has been neither passed nor satisfied by a course
+    #[serde(rename = "proficiency")]
+    Proficiency { path: Path, proficiency: String },
+    /// A query assertion (eg, "at least 3 courses") that is not yet met
+    #[serde(rename = "assertion")]
+    Assertion {
+        path: Path,
+        key: String,
+        operator: String,
+        expected: String,
+        resolved: String,
+        remaining: Option<String>,
+    },
+    /// A count-of rule which still has unfilled slots
+    #[serde(rename = "count")]
+    Count {
+        path: Path,
+        needed: usize,
+        satisfied: usize,
+    },
+}
+
+impl Gap {
+    pub fn path(&self) -> &Path {
+        match self {
+            Gap::Course { path, .. }
+            | Gap::Proficiency { path, .. }
+            | Gap::Assertion { path, .. }
+            | Gap::Count { path, .. } => path,
+        }
+    }
+}
+
+impl Gap {
+    /// What the student still needs, in the catalog's words
+    pub fn describe(&self, messages: &Catalog) -> String {
+        match self {
+            Gap::Course { course, .. } => messages.format("gap.course", &[course]),
+            Gap::Proficiency { proficiency, .. } => {
+                messages.format("gap.proficiency", &[proficiency])
+            }
+            Gap::Count {
+                needed, satisfied, ..
+            } => messages.format("gap.count", &[&needed.saturating_sub(*satisfied), needed]),
+            Gap::Assertion {
+                key,
+                operator,
+                expected,
+                resolved,
+                remaining,
+                ..
+            } => {
+                // "count/distinct-courses" => "distinct courses"
+                let noun = key.rsplit('/').next().unwrap_or(key).replace('-', " ");
+
+                match remaining {
+                    Some(remaining) => messages.format("gap.remaining", &[remaining, &noun]),
+                    None => messages.format("gap.compare", &[&noun, operator, expected, resolved]),
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Gap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.describe(&Catalog::english()))
+    }
+}
+
+// pending statuses already have something in the pipeline, so they aren't gaps
+fn is_missing(status: &RuleStatus) -> bool {
+    matches!(
+        status,
+        RuleStatus::NeedsMoreItems | RuleStatus::Empty | RuleStatus::FailedInvariant
+    )
+}
+
+impl AreaOfStudy {
+    pub fn gaps(&self) -> Vec<Gap> {
+        self.result.gaps()
+    }
+
+    /// Each gap, along with the name of the innermost unfinished requirement that contains it.
+    /// Gaps outside of any requirement are attributed to the area itself.
+    pub fn requirement_gaps(&self) -> Vec<(String, Gap)> {
+        let requirements = self.unsatisfied_requirements();
+
+        self.gaps()
+            .into_iter()
+            .map(|gap| {
+                let owner = requirements
+                    .iter()
+                    .filter(|r| gap.path().0.starts_with(&r.path.0))
+                    .max_by_key(|r| r.path.0.len())
+                    .map_or_else(|| self.name.clone(), |r| r.name.clone());
+
+                (owner, gap)
+            })
+            .collect()
+    }
+}
+
+impl Rule {
+    pub fn gaps(&self) -> Vec<Gap> {
+        let mut gaps = Vec::new();
+        self.collect_gaps(&mut gaps);
+        gaps
+    }
+
+    fn collect_gaps(&self, gaps: &mut Vec<Gap>) {
+        if !is_missing(self.status()) {
+            return;
+        }
+
+        match self {
+            Rule::Count(r) => {
+                let satisfied = r
+                    .items
+                    .iter()
+                    .filter(|item| item.status().is_passing())
+                    .count();
+
+                if !r.at_most && satisfied < r.count {
+                    gaps.push(Gap::Count {
+                        path: r.path.clone(),
+                        needed: r.count,
+                        satisfied,
+                    });
+                }
+
+                for item in &r.items {
+                    item.collect_gaps(gaps);
+                }
+            }
+            Rule::Course(r) => {
+                if r.optional {
+                    return;
+                }
+
+                let course = r.label();
+
+                gaps.push(Gap::Course {
+                    path: r.path.clone(),
+                    course,
+                });
+            }
+            Rule::Proficiency(r) => gaps.push(Gap::Proficiency {
+                path: r.path.clone(),
+                proficiency: r.proficiency.clone(),
+            }),
+            Rule::Requirement(r) => {
+                if let Some(result) = &r.result {
+                    result.collect_gaps(gaps);
+                }
+            }
+            Rule::Query(r) => {
+                for a in r.assertions.iter().filter_map(|a| a.active_rule()) {
+                    if is_missing(&a.status) {
+                        gaps.push(a.to_gap());
+                    }
+                }
+            }
+            Rule::Conditional(r) => {
+                if let Some(branch) = r.active_branch() {
+                    branch.collect_gaps(gaps);
+                }
+            }
+        }
+    }
+}
+
+impl AssertionRule {
+    fn to_gap(&self) -> Gap {
+        let resolved = self.resolved.clone().unwrap_or_else(|| "0".into());
+
+        let remaining = match self.operator {
+            Operator::GreaterThanOrEqualTo | Operator::GreaterThan | Operator::EqualTo => {
+                match (
+                    self.expected.parse::<Decimal>(),
+                    resolved.parse::<Decimal>(),
+                ) {
+                    (Ok(expected), Ok(has)) => {
+                        Some(std::cmp::max(Decimal::new(0, 0), expected - has).to_string())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        Gap::Assertion {
+            path: self.path.clone(),
+            key: self.key.to_string(),
+            operator: self.operator.to_string(),
+            expected: self.expected.clone(),
+            resolved,
+            remaining,
+        }
+    }
+}
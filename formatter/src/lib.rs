@@ -2,13 +2,23 @@ pub mod area_of_study;
 pub mod assertion;
 pub mod audit;
 pub mod claim;
+pub mod diff;
 pub mod filter_predicate;
+pub mod gaps;
 pub mod limit;
+pub mod messages;
 pub mod operator;
 pub mod path;
 pub mod predicate_expression;
 pub mod rule;
 pub(crate) mod serde;
 pub mod student;
+pub mod term;
+pub mod to_dot;
+pub mod to_latex;
+pub mod to_markdown;
 pub mod to_prose;
 pub mod to_record;
+pub mod to_summary;
+pub mod to_transcript;
+pub mod validate;
@@ -1,20 +1,49 @@
+use crate::filter_predicate::CompoundPredicate;
 use crate::student::Student;
 use crate::to_prose::{ProseOptions, ToProse};
 use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum LimitKind {
+    #[default]
+    Courses,
+    Credits,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Limit {
     at_most: String,
+    #[serde(default)]
+    at_most_what: LimitKind,
+    #[serde(rename = "where")]
+    filter: Option<CompoundPredicate>,
+    message: Option<String>,
 }
 
 impl ToProse for Limit {
     fn to_prose(
         &self,
         f: &mut std::fmt::Formatter<'_>,
-        _student: &Student,
-        _options: &ProseOptions,
-        _indent: usize,
+        student: &Student,
+        options: &ProseOptions,
+        indent: usize,
     ) -> std::fmt::Result {
-        write!(f, "at most {}", self.at_most)
+        let key = match self.at_most_what {
+            LimitKind::Courses => "limit.courses",
+            LimitKind::Credits => "limit.credits",
+        };
+
+        write!(f, "{}", options.messages.format(key, &[&self.at_most]))?;
+
+        if let Some(filter) = &self.filter {
+            write!(f, " {} ", options.messages.get("limit.where"))?;
+            filter.to_prose(f, student, options, indent)?;
+        }
+
+        if let Some(message) = &self.message {
+            write!(f, " [{}]", message)?;
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("count.at-most", "at most {0} of {1}"),
+    ("count.either", "either of (these 2)"),
+    ("count.both", "both of (these 2)"),
+    ("count.all", "all of (these {0})"),
+    ("count.any", "any of (these {0})"),
+    ("count.at-least", "at least {0} of {1}"),
+    ("count.progress-max", "(ok: {0}, max: {1})"),
+    ("count.progress-need", "(ok: {0}, need: {1})"),
+    ("assertion.needs", "needs … {0}"),
+    ("assertion.not", "not … {0}"),
+    ("assertion.fewer-than", "fewer than … {0}"),
+    ("assertion.at-most", "at most … {0}"),
+    ("assertion.more-than", "more than … {0}"),
+    ("assertion.at-least", "at least … {0}"),
+    ("assertion.needs-has", "{0}: needs {1}, has {2}"),
+    ("assertion.compare-has", "{0} {1} {2} (has {3})"),
+    ("assertion.noun.areas", "areas"),
+    ("assertion.noun.courses", "courses"),
+    ("assertion.noun.distinct-courses", "distinct courses"),
+    ("assertion.noun.performances", "performances"),
+    ("assertion.noun.recitals", "recitals"),
+    ("assertion.noun.subjects", "subjects"),
+    ("assertion.noun.terms", "terms"),
+    ("assertion.noun.years", "years"),
+    (
+        "assertion.noun.terms-from-most-common-course",
+        "terms from the most common course",
+    ),
+    (
+        "assertion.noun.terms-from-most-common-course-by-name",
+        "terms from the most common course, by name",
+    ),
+    ("assertion.noun.credits", "credits"),
+    (
+        "assertion.noun.credits-from-single-subject",
+        "credits from a single subject",
+    ),
+    ("assertion.noun.grade", "grade"),
+    (
+        "assertion.noun.religious-traditions",
+        "religious traditions",
+    ),
+    ("assertion.noun.regions", "regions"),
+    ("assertion.noun.perspectives", "perspectives"),
+    ("course.any-level", "any {0}-level {1} course"),
+    ("course.any", "any {0} course"),
+    ("course.substituted", "(as {0})"),
+    ("course.min-grade", "(grade ≥ {0})"),
+    ("course.optional", "(optional)"),
+    ("course.shared", "(shared)"),
+    ("proficiency.label", "Proficiency: {0}"),
+    ("proficiency.prose", "Proficiency({0})"),
+    ("proficiency.passed", "[passed by proficiency]"),
+    ("query.given", "Given {0}"),
+    ("query.given-matching", "Given {0} matching"),
+    ("query.matching", "Matching courses:"),
+    ("query.matching-shared", "Matching courses (shared):"),
+    (
+        "query.failures",
+        "Pre-claimed courses which cannot be re-claimed:",
+    ),
+    ("query.assertions", "There must be:"),
+    ("limit.heading", "Subject to these limits:"),
+    ("limit.courses", "at most {0} courses"),
+    ("limit.credits", "at most {0} credits"),
+    ("limit.where", "where"),
+    ("requirement.label", "Requirement({0})"),
+    ("requirement.audited", "is manually audited"),
+    ("requirement.audited-by", "is manually audited by the {0}"),
+    ("requirement.audited-cell", "audited by {0}"),
+    ("auditor.department", "department"),
+    ("auditor.registrar", "registrar"),
+    ("auditor.music-office", "music office"),
+    ("requirement.contract", "is a contract requirement"),
+    ("conditional.if", "If: [{0}]"),
+    ("conditional.then", "Then ({0})"),
+    ("conditional.else", "Else ({0})"),
+    ("conditional.do-nothing", "do nothing"),
+    ("gap.course", "needs {0}"),
+    ("gap.proficiency", "needs the {0} proficiency"),
+    ("gap.count", "needs {0} more of its {1} parts"),
+    ("gap.remaining", "needs {0} more {1}"),
+    ("gap.compare", "needs {0} {1} {2} (has {3})"),
+    ("graph.either", "either"),
+    ("graph.all", "all of {0}"),
+    ("graph.some", "{0} of {1}"),
+    ("graph.proficiency", "proficiency: {0}"),
+    ("graph.given", "given {0}"),
+    ("graph.if", "if {0}"),
+    ("latex.status", "Status: {0}"),
+    ("latex.gpa", "GPA: {0}"),
+    ("latex.waived", "(waived)"),
+    ("latex.waiver.override", "Waived by an exception."),
+    (
+        "latex.waiver.inserted",
+        "Satisfied by a course inserted by an exception.",
+    ),
+    (
+        "latex.waiver.optional",
+        "Optional, so it does not need to be completed.",
+    ),
+    (
+        "latex.waiver.assertions",
+        "Every assertion was waived by an exception.",
+    ),
+    ("latex.waiver.items", "Every item beneath it was waived."),
+    ("latex.status.done", "complete"),
+    ("latex.status.waived", "waived"),
+    ("latex.status.pending-current", "in progress"),
+    ("latex.status.pending-registered", "registered"),
+    ("latex.status.pending-approval", "pending approval"),
+    ("latex.status.needs-more-items", "incomplete"),
+    ("latex.status.empty", "not started"),
+    ("latex.status.failed-invariant", "failed"),
+    (
+        "summary.heading",
+        "Unfinished requirements, closest to completion first:",
+    ),
+    ("summary.percent-complete", "{0} ({1}% complete)"),
+    ("status.label", "status"),
+    ("status.done", "Done"),
+    ("status.waived", "Waived"),
+    ("status.needs-more-items", "NeedsMoreItems"),
+    ("status.pending-current", "PendingCurrent"),
+    ("status.pending-registered", "PendingRegistered"),
+    ("status.pending-approval", "PendingApproval"),
+    ("status.empty", "Empty"),
+    ("status.failed-invariant", "FailedInvariant"),
+];
+
+/// A set of translated prose strings, keyed by message id.
+///
+/// Messages may contain positional placeholders ("{0}", "{1}", …). Any message
+/// missing from a loaded catalog falls back to the built-in English text.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: BTreeMap<String, String>,
+}
+
+impl Catalog {
+    pub fn english() -> Catalog {
+        Catalog::default()
+    }
+
+    /// Loads a catalog from a flat JSON object of message ids to strings
+    pub fn from_json(json: &str) -> serde_json::Result<Catalog> {
+        Ok(Catalog {
+            messages: serde_json::from_str(json)?,
+        })
+    }
+
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        if let Some(message) = self.messages.get(key) {
+            return message;
+        }
+
+        ENGLISH
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map_or(key, |(_, message)| message)
+    }
+
+    pub fn format(&self, key: &str, args: &[&dyn Display]) -> String {
+        let mut message = self.get(key).to_string();
+
+        for (i, arg) in args.iter().enumerate() {
+            message = message.replace(&format!("{{{}}}", i), &arg.to_string());
+        }
+
+        message
+    }
+}
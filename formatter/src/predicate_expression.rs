@@ -90,11 +90,15 @@ where
             Some(false) => "f!",
         };
 
-        write!(
-            f,
-            "({:?} \"{:?}\" => {})",
-            self.function, self.argument, headline
-        )
+        let function = match &self.function {
+            Some(func) => func.to_string(),
+            None => String::from("?"),
+        };
+
+        match &self.argument {
+            Some(arg) => write!(f, "({}({:?}) => {})", function, arg, headline),
+            None => write!(f, "({}() => {})", function, headline),
+        }
     }
 }
 
@@ -216,8 +220,8 @@ impl Display for DynamicPredicateConditionFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use DynamicPredicateConditionFunction as F;
         let output = match self {
-            F::QueryHasCourseWithAttribute => "has-ip-course",
-            F::QueryHasSingleCourseWithAttribute => "has-completed-course",
+            F::QueryHasCourseWithAttribute => "query-has-course-with-attribute",
+            F::QueryHasSingleCourseWithAttribute => "query-has-single-course-with-attribute",
         };
         f.write_str(output)
     }
@@ -17,6 +17,17 @@ pub struct ConditionalRule {
     pub when_true: Box<Rule>,
 }
 
+impl ConditionalRule {
+    /// The branch that the condition selected, if it was evaluated and that branch exists
+    pub fn active_branch(&self) -> Option<&Rule> {
+        match self.condition.result() {
+            Some(true) => Some(&self.when_true),
+            Some(false) => self.when_false.as_deref(),
+            None => None,
+        }
+    }
+}
+
 impl ToProse for ConditionalRule {
     fn to_prose(
         &self,
@@ -31,25 +42,44 @@ impl ToProse for ConditionalRule {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "If: [{}]", self.condition)?;
+        writeln!(
+            f,
+            "{}",
+            options
+                .messages
+                .format("conditional.if", &[&self.condition])
+        )?;
 
         let branch = self.condition.result();
         let true_branch = if branch == Some(true) { "t." } else { "" };
         let false_branch = if branch == Some(false) { "f!" } else { "" };
 
-        writeln!(f, "Then ({})", true_branch)?;
+        write!(f, "{}", " ".repeat(indent * 4))?;
+        writeln!(
+            f,
+            "{}",
+            options.messages.format("conditional.then", &[&true_branch])
+        )?;
 
         self.when_true.to_prose(f, student, options, indent + 1)?;
 
         writeln!(f)?;
 
-        writeln!(f, "Else ({})", false_branch)?;
+        write!(f, "{}", " ".repeat(indent * 4))?;
+        writeln!(
+            f,
+            "{}",
+            options
+                .messages
+                .format("conditional.else", &[&false_branch])
+        )?;
 
         if let Some(pred) = &self.when_false {
             pred.to_prose(f, student, options, indent + 1)?;
             writeln!(f)?;
         } else {
-            write!(f, "do nothing")?;
+            write!(f, "{}", " ".repeat((indent + 1) * 4))?;
+            writeln!(f, "{}", options.messages.get("conditional.do-nothing"))?;
         }
 
         Ok(())
@@ -100,7 +130,7 @@ impl ToRecord for ConditionalRule {
             None => vec![],
         };
 
-        true_reqs.extend(false_reqs.into_iter());
+        true_reqs.extend(false_reqs);
 
         true_reqs
     }
@@ -8,10 +8,14 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CountRule {
     pub count: usize,
+    #[serde(default)]
+    pub at_most: bool,
     pub audit_status: RuleStatus,
     pub audit: Vec<AuditResult>,
     pub items: Vec<Box<Rule>>,
     pub max_rank: String,
+    #[serde(default)]
+    pub overridden: bool,
     pub path: Path,
     pub rank: String,
     pub status: RuleStatus,
@@ -19,10 +23,9 @@ pub struct CountRule {
 
 impl CountRule {
     pub fn all_items_are_requirements(&self) -> bool {
-        self.items.iter().all(|r| match r.as_ref() {
-            Rule::Requirement(_) => true,
-            _ => false,
-        })
+        self.items
+            .iter()
+            .all(|r| matches!(r.as_ref(), Rule::Requirement(_)))
     }
 }
 
@@ -51,22 +54,31 @@ impl ToProse for CountRule {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "status: {:?}", self.status)?;
+        writeln!(
+            f,
+            "{}: {}",
+            options.messages.get("status.label"),
+            options.paint_status(&self.status)
+        )?;
 
         write!(f, "{}", " ".repeat(indent * 4))?;
         let size = self.items.len();
 
-        if self.count == 1 && size == 2 {
-            write!(f, "either of (these 2)")?;
+        let m = &options.messages;
+        let shape = if self.at_most {
+            m.format("count.at-most", &[&self.count, &size])
+        } else if self.count == 1 && size == 2 {
+            m.format("count.either", &[])
         } else if self.count == 2 && size == 2 {
-            write!(f, "both of (these 2)")?;
-        } else if (self.count as usize) == size {
-            write!(f, "all of (these {})", size)?;
+            m.format("count.both", &[])
+        } else if self.count == size {
+            m.format("count.all", &[&size])
         } else if self.count == 2 {
-            write!(f, "any of (these {})", size)?;
+            m.format("count.any", &[&size])
         } else {
-            write!(f, "at least {} of {}", self.count, size)?;
-        }
+            m.format("count.at-least", &[&self.count, &size])
+        };
+        write!(f, "{}", shape)?;
 
         let ok_count = self
             .items
@@ -74,7 +86,12 @@ impl ToProse for CountRule {
             .filter(|r| r.status().is_passing())
             .count();
 
-        write!(f, " (ok: {}, need: {})", ok_count, self.count)?;
+        let progress = if self.at_most {
+            m.format("count.progress-max", &[&ok_count, &self.count])
+        } else {
+            m.format("count.progress-need", &[&ok_count, &self.count])
+        };
+        write!(f, " {}", progress)?;
 
         writeln!(f)?;
 
@@ -87,7 +104,7 @@ impl ToProse for CountRule {
             )?;
 
             write!(f, "{}", " ".repeat((indent + 1) * 4))?;
-            writeln!(f, "There must be:")?;
+            writeln!(f, "{}", options.messages.get("query.assertions"))?;
 
             for (i, a) in self.audit.iter().enumerate() {
                 writeln!(f, "{}.", i + 1)?;
@@ -121,7 +138,7 @@ impl ToRecord for CountRule {
 
         let show_prefix = !self.all_items_are_requirements();
 
-        if self.count == 1 {
+        if self.count == 1 && self.items.len() > 1 {
             let item = self.items.iter().find(|r| r.status().is_passing());
 
             match item {
@@ -130,7 +147,11 @@ impl ToRecord for CountRule {
                         row.push(Record {
                             title: "1 of these".to_string(),
                             subtitle: None,
-                            status: RecordStatus::Waived,
+                            status: if is_waived {
+                                RecordStatus::Waived
+                            } else {
+                                sub_record.status
+                            },
                             content: sub_record.content,
                         });
                     }
@@ -1,7 +1,9 @@
 use crate::claim::Claim;
+use crate::messages::Catalog;
 use crate::path::Path;
 use crate::rule::RuleStatus;
 use crate::student::Student;
+use crate::term::Term;
 use crate::to_prose::{ProseOptions, ToProse};
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +16,8 @@ pub struct CourseRule {
     pub max_rank: String,
     #[serde(deserialize_with = "crate::serde::empty_str_as_none")]
     pub course: Option<String>,
+    #[serde(default)]
+    pub wildcard: Option<CourseWildcard>,
     #[serde(deserialize_with = "crate::serde::empty_str_as_none")]
     pub ap: Option<String>,
     pub institution: Option<String>,
@@ -21,6 +25,55 @@ pub struct CourseRule {
     pub grade: Option<String>,
     pub name: Option<String>,
     pub crsid: Option<String>,
+    pub year: Option<u16>,
+    pub term: Option<String>,
+    #[serde(default)]
+    pub optional: bool,
+    #[serde(default)]
+    pub allow_claimed: bool,
+    #[serde(default)]
+    pub inserted: bool,
+    #[serde(default)]
+    pub overridden: bool,
+}
+
+/// A wildcard spec like "CHEM 2XX" or "MATH 3**", as parsed by the engine: the subject, and the
+/// level as a full course number (200), if the spec gave one
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CourseWildcard {
+    pub subject: String,
+    pub level: Option<u32>,
+}
+
+impl CourseRule {
+    /// The course this rule asks for, as written in the spec
+    pub fn label(&self) -> String {
+        self.course
+            .clone()
+            .or_else(|| self.ap.clone())
+            .or_else(|| self.name.clone())
+            .unwrap_or_else(|| "?????".to_string())
+    }
+
+    /// The course this rule asks for, with wildcard specs put into words, as in
+    /// "any 200-level CHEM course"
+    pub fn describe(&self, messages: &Catalog) -> String {
+        match &self.wildcard {
+            Some(CourseWildcard {
+                subject,
+                level: Some(level),
+            }) => messages.format("course.any-level", &[level, subject]),
+            Some(CourseWildcard { subject, .. }) => messages.format("course.any", &[subject]),
+            None => self.label(),
+        }
+    }
+
+    pub fn required_term(&self) -> Option<Term> {
+        match (&self.year, &self.term) {
+            (Some(year), Some(term)) => Term::from_parts(&year.to_string(), term).ok(),
+            _ => None,
+        }
+    }
 }
 
 impl ToProse for CourseRule {
@@ -48,7 +101,7 @@ impl ToProse for CourseRule {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        let matched_course = if let Some(claim) = self.claims.get(0) {
+        let matched_course = if let Some(claim) = self.claims.first() {
             student.get_class_by_clbid(&claim.clbid)
         } else {
             None
@@ -56,28 +109,59 @@ impl ToProse for CourseRule {
 
         let status = matched_course.map_or("", |c| c.calculate_symbol(&self.status));
 
-        write!(f, "{} ", status)?;
+        write!(f, "{} ", options.paint(status, &self.status))?;
 
         match (&self.status, matched_course, &self.course, &self.ap) {
             (RuleStatus::Waived, Some(m), _, _) => write!(f, "{} {}", m.course, m.name)?,
             (_, Some(m), _, _) if &m.course_type == "ap" => write!(f, "{}", m.name)?,
-            (_, Some(m), None, Some(ap)) if ap != "" => write!(f, "{} {}", m.course, m.name)?,
-            (_, _, Some(c), _) => write!(f, "{}", c)?,
+            (_, Some(m), None, Some(ap)) if !ap.is_empty() => write!(f, "{} {}", m.course, m.name)?,
+            (_, Some(m), Some(_), _) if self.wildcard.is_some() => {
+                write!(f, "{} ({})", self.describe(&options.messages), m.course)?
+            }
+            (_, _, Some(_), _) => write!(f, "{}", self.describe(&options.messages))?,
             (_, _, _, _) => write!(f, "?????")?,
         };
 
+        if let Some(original) = self.claims.first().and_then(|c| c.substituted_for.as_ref()) {
+            write!(
+                f,
+                " {}",
+                options.messages.format("course.substituted", &[original])
+            )?;
+        }
+
+        if let Some(grade) = &self.grade {
+            write!(
+                f,
+                " {}",
+                options.messages.format("course.min-grade", &[grade])
+            )?;
+        }
+
+        if let Some(term) = self.required_term() {
+            write!(f, " ({})", term)?;
+        }
+
         if let Some(inst) = &self.institution {
             write!(f, " [{}]", inst)?;
         }
 
+        if self.optional {
+            write!(f, " {}", options.messages.get("course.optional"))?;
+        }
+
+        if self.allow_claimed && matched_course.is_some() {
+            write!(f, " {}", options.messages.get("course.shared"))?;
+        }
+
         writeln!(f)
     }
 }
 
 use crate::to_record::{Cell, Record, RecordOptions, RecordStatus, ToRecord};
 impl ToRecord for CourseRule {
-    fn get_row(&self, student: &Student, _options: &RecordOptions, is_waived: bool) -> Vec<Record> {
-        let course = if let Some(claim) = self.claims.get(0) {
+    fn get_row(&self, student: &Student, options: &RecordOptions, is_waived: bool) -> Vec<Record> {
+        let course = if let Some(claim) = self.claims.first() {
             student.get_class_by_clbid(&claim.clbid)
         } else {
             None
@@ -93,7 +177,16 @@ impl ToRecord for CourseRule {
 
         let (body, status) = if let Some(course) = course {
             // if there's a course, show it, even if it was "waived" (ie, it was inserted)
-            if is_waived {
+            if options.mark_exceptions && self.inserted {
+                (
+                    Some(Cell::Text(format!("{} [ins]", course.semi_verbose()))),
+                    if is_waived {
+                        RecordStatus::Waived
+                    } else {
+                        RecordStatus::Done
+                    },
+                )
+            } else if is_waived {
                 (
                     Some(Cell::SingleCourse(course.clone())),
                     RecordStatus::Waived,
@@ -101,6 +194,17 @@ impl ToRecord for CourseRule {
             } else {
                 (Some(Cell::SingleCourse(course.clone())), RecordStatus::Done)
             }
+        } else if self.optional {
+            // the engine waives unmatched optional courses; say why the cell is empty
+            (
+                Some(Cell::Text(String::from("optional"))),
+                RecordStatus::Waived,
+            )
+        } else if is_waived && options.mark_exceptions {
+            (
+                Some(Cell::Text(String::from("[waived]"))),
+                RecordStatus::Waived,
+            )
         } else if is_waived {
             (None, RecordStatus::Waived)
         } else if self.status == RuleStatus::Empty {
@@ -118,7 +222,7 @@ impl ToRecord for CourseRule {
         vec![Record {
             title: header,
             subtitle: None,
-            status: status,
+            status,
             content: body,
         }]
     }
@@ -1,7 +1,8 @@
-use crate::student::Student;
+use crate::student::{ClassLabId, Student};
 use crate::to_prose::{ProseOptions, ToProse};
 use crate::to_record::{Record, RecordOptions, ToRecord};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 
 pub mod conditional;
 pub mod count;
@@ -78,7 +79,31 @@ impl ToRecord for Rule {
 }
 
 impl Rule {
-    fn status(&self) -> &RuleStatus {
+    /// Every course claimed anywhere beneath this rule
+    pub fn claimed_clbids(&self) -> BTreeSet<ClassLabId> {
+        match self {
+            Rule::Count(r) => r.items.iter().flat_map(|i| i.claimed_clbids()).collect(),
+            Rule::Requirement(r) => match &r.result {
+                Some(result) => result.claimed_clbids(),
+                None => BTreeSet::new(),
+            },
+            Rule::Conditional(r) => {
+                let mut clbids = r.when_true.claimed_clbids();
+                if let Some(when_false) = &r.when_false {
+                    clbids.extend(when_false.claimed_clbids());
+                }
+                clbids
+            }
+            Rule::Course(r) => r.claims.iter().map(|c| c.clbid.clone()).collect(),
+            Rule::Proficiency(r) => match &r.course {
+                Some(course) => course.claims.iter().map(|c| c.clbid.clone()).collect(),
+                None => BTreeSet::new(),
+            },
+            Rule::Query(r) => r.claims.iter().map(|c| c.clbid.clone()).collect(),
+        }
+    }
+
+    pub(crate) fn status(&self) -> &RuleStatus {
         match self {
             Rule::Count(r) => &r.status,
             Rule::Course(r) => &r.status,
@@ -137,6 +162,19 @@ impl RuleStatus {
         }
     }
 
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            RuleStatus::Done => "status.done",
+            RuleStatus::Waived => "status.waived",
+            RuleStatus::NeedsMoreItems => "status.needs-more-items",
+            RuleStatus::PendingCurrent => "status.pending-current",
+            RuleStatus::PendingRegistered => "status.pending-registered",
+            RuleStatus::PendingApproval => "status.pending-approval",
+            RuleStatus::Empty => "status.empty",
+            RuleStatus::FailedInvariant => "status.failed-invariant",
+        }
+    }
+
     pub fn is_waived(&self) -> bool {
         match self {
             RuleStatus::Waived => true,
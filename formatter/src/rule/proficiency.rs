@@ -12,7 +12,19 @@ pub struct ProficiencyRule {
     pub rank: String,
     pub max_rank: String,
     pub proficiency: String,
+    pub proficiency_status: Option<RuleStatus>,
     pub course: Option<CourseRule>,
+    #[serde(default)]
+    pub overridden: bool,
+}
+
+impl ProficiencyRule {
+    /// Whether the student has passed the proficiency itself (eg, by exam),
+    /// as opposed to satisfying it with a course.
+    pub fn passed_by_proficiency(&self) -> bool {
+        self.proficiency_status
+            .is_some_and(|status| status.is_passing())
+    }
 }
 
 impl ToProse for ProficiencyRule {
@@ -41,7 +53,19 @@ impl ToProse for ProficiencyRule {
 
         write!(f, "{}", " ".repeat(indent * 4))?;
 
-        write!(f, "{:?} ", self.status)?;
+        write!(f, "{} ", options.paint_status(&self.status))?;
+
+        write!(
+            f,
+            "{}",
+            options
+                .messages
+                .format("proficiency.prose", &[&self.proficiency])
+        )?;
+
+        if self.passed_by_proficiency() {
+            write!(f, " {}", options.messages.get("proficiency.passed"))?;
+        }
 
         writeln!(f)?;
 
@@ -53,13 +77,26 @@ impl ToProse for ProficiencyRule {
     }
 }
 
-use crate::to_record::{Record, RecordOptions, RecordStatus, ToRecord};
+use crate::to_record::{Cell, Record, RecordOptions, RecordStatus, ToRecord};
 impl ToRecord for ProficiencyRule {
     fn get_row(&self, student: &Student, options: &RecordOptions, is_waived: bool) -> Vec<Record> {
         let is_waived = is_waived || self.status.is_waived();
 
         let header = self.proficiency.clone();
 
+        if self.passed_by_proficiency() {
+            return vec![Record {
+                title: header,
+                subtitle: None,
+                status: if is_waived {
+                    RecordStatus::Waived
+                } else {
+                    self.status
+                },
+                content: vec![Cell::Text(String::from("proficiency"))],
+            }];
+        }
+
         if let Some(course_rule) = &self.course {
             let mut row = course_rule.get_row(student, options, is_waived);
             if row.len() != 1 {
@@ -86,7 +123,7 @@ impl ToRecord for ProficiencyRule {
         vec![Record {
             title: header,
             subtitle: None,
-            status: status,
+            status,
             content: body,
         }]
     }
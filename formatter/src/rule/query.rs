@@ -23,6 +23,18 @@ pub enum QuerySource {
     MusicRecitals,
 }
 
+impl std::fmt::Display for QuerySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuerySource::Courses => write!(f, "courses"),
+            QuerySource::ClaimedCourses => write!(f, "claimed courses"),
+            QuerySource::Areas => write!(f, "areas of study"),
+            QuerySource::MusicPerformances => write!(f, "music performances"),
+            QuerySource::MusicRecitals => write!(f, "music recitals"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum DataType {
     #[serde(rename = "course")]
@@ -57,6 +69,8 @@ pub struct QueryRule {
     pub inserted: Vec<ClassLabId>,
     pub limit: Vec<Limit>,
     pub max_rank: String,
+    #[serde(default)]
+    pub overridden: bool,
     pub path: Path,
     pub rank: String,
     pub source: QuerySource,
@@ -75,7 +89,7 @@ impl QueryRule {
 
         known_clbids
             .iter()
-            .map(|clbid| student.get_class_by_clbid(&clbid).unwrap())
+            .map(|clbid| student.get_class_by_clbid(clbid).unwrap())
             .collect()
     }
 
@@ -114,11 +128,22 @@ impl ToProse for QueryRule {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "status: {:?}", self.status)?;
+        writeln!(
+            f,
+            "{}: {}",
+            options.messages.get("status.label"),
+            options.paint_status(&self.status)
+        )?;
 
         if let Some(filter) = &self.filter {
             write!(f, "{}", " ".repeat(indent * 4))?;
-            writeln!(f, "Given courses matching")?;
+            writeln!(
+                f,
+                "{}",
+                options
+                    .messages
+                    .format("query.given-matching", &[&self.source])
+            )?;
             write!(f, "{}", " ".repeat((indent + 1) * 4))?;
             filter.to_prose(f, student, options, indent + 1)?;
             writeln!(f)?;
@@ -126,7 +151,7 @@ impl ToProse for QueryRule {
 
         if !self.limit.is_empty() {
             write!(f, "{}", " ".repeat(indent * 4))?;
-            writeln!(f, "Subject to these limits:")?;
+            writeln!(f, "{}", options.messages.get("limit.heading"))?;
             for l in &self.limit {
                 write!(f, "{}", " ".repeat(indent * 4))?;
                 write!(f, "- ")?;
@@ -137,7 +162,11 @@ impl ToProse for QueryRule {
 
         if !self.claims.is_empty() {
             write!(f, "{}", " ".repeat(indent * 4))?;
-            writeln!(f, "Matching courses:")?;
+            if self.allow_claimed {
+                writeln!(f, "{}", options.messages.get("query.matching-shared"))?;
+            } else {
+                writeln!(f, "{}", options.messages.get("query.matching"))?;
+            }
 
             for clm in &self.claims {
                 if let Some(course) = student.get_class_by_clbid(&clm.clbid) {
@@ -157,7 +186,7 @@ impl ToProse for QueryRule {
 
         if !self.failures.is_empty() {
             write!(f, "{}", " ".repeat(indent * 4))?;
-            writeln!(f, "Pre-claimed courses which cannot be re-claimed:")?;
+            writeln!(f, "{}", options.messages.get("query.failures"))?;
             for clm in &self.failures {
                 write!(f, "{}", " ".repeat((indent + 1) * 4))?;
                 if let Some(course) = student.get_class_by_clbid(&clm.clbid) {
@@ -170,7 +199,7 @@ impl ToProse for QueryRule {
         }
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "There must be:")?;
+        writeln!(f, "{}", options.messages.get("query.assertions"))?;
         for (i, a) in self.assertions.iter().enumerate() {
             write!(f, "{}", " ".repeat((indent + 1) * 4))?;
             writeln!(f, "{}.", i + 1)?;
@@ -196,7 +225,7 @@ impl ToRecord for QueryRule {
             .filter(|a| a.is_at_least());
 
         for assertion in course_and_credit_assertions.take(1) {
-            row.extend(assertion.get_row(student, options, is_waived).into_iter());
+            row.extend(assertion.get_row(student, options, is_waived));
         }
 
         row
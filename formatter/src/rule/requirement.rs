@@ -4,19 +4,96 @@ use crate::student::Student;
 use crate::to_prose::{ProseOptions, ToProse};
 use serde::{Deserialize, Serialize};
 
+/// Emphases are written as top-level requirements named "Emphasis: …"
+pub const EMPHASIS_PREFIX: &str = "Emphasis: ";
+
+pub fn is_emphasis_name(name: &str) -> bool {
+    name.starts_with(EMPHASIS_PREFIX)
+}
+
+/// Who signs off on a requirement that the audit cannot check by itself
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditedBy {
+    Department,
+    Registrar,
+    MusicOffice,
+    #[default]
+    None,
+}
+
+impl AuditedBy {
+    pub fn message_key(&self) -> Option<&'static str> {
+        match self {
+            AuditedBy::Department => Some("auditor.department"),
+            AuditedBy::Registrar => Some("auditor.registrar"),
+            AuditedBy::MusicOffice => Some("auditor.music-office"),
+            AuditedBy::None => None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Requirement {
-    // pub contract: bool,
+    #[serde(default)]
+    pub is_contract: bool,
     pub is_audited: bool,
+    #[serde(default, deserialize_with = "crate::serde::null_as_default")]
+    pub audited_by: AuditedBy,
     pub max_rank: String,
     pub message: Option<String>,
     pub name: String,
+    #[serde(default)]
+    pub overridden: bool,
     pub path: Path,
     pub rank: String,
     pub result: Option<Box<Rule>>,
     pub status: RuleStatus,
 }
 
+impl Requirement {
+    fn summary_record(&self, subtitle: &str, status: RuleStatus, text: String) -> Record {
+        Record {
+            title: self.name.clone(),
+            subtitle: Some(subtitle.to_string()),
+            status,
+            content: vec![Cell::Text(text)],
+        }
+    }
+
+    fn fractional_rank(&self) -> String {
+        use rust_decimal::Decimal;
+
+        match (
+            self.rank.parse::<Decimal>(),
+            self.max_rank.parse::<Decimal>(),
+        ) {
+            (Ok(rank), Ok(max_rank)) if max_rank != Decimal::new(0, 0) => {
+                (rank / max_rank).round_dp(2).to_string()
+            }
+            _ => format!("{} / {}", self.rank, self.max_rank),
+        }
+    }
+
+    /// Who audits this requirement. Results from before `audited_by` existed only say
+    /// whether the department does.
+    pub fn auditor(&self) -> AuditedBy {
+        match self.audited_by {
+            AuditedBy::None if self.is_audited => AuditedBy::Department,
+            auditor => auditor,
+        }
+    }
+
+    pub fn is_emphasis(&self) -> bool {
+        is_emphasis_name(&self.name)
+    }
+
+    /// The emphasis name, without the "Emphasis: " prefix
+    pub fn emphasis_name(&self) -> Option<&str> {
+        self.name.strip_prefix(EMPHASIS_PREFIX)
+    }
+}
+
 impl ToProse for Requirement {
     fn to_prose(
         &self,
@@ -42,14 +119,35 @@ impl ToProse for Requirement {
         };
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "status: {:?}", self.status)?;
+        writeln!(
+            f,
+            "{}: {}",
+            options.messages.get("status.label"),
+            options.paint_status(&self.status)
+        )?;
 
         write!(f, "{}", " ".repeat(indent * 4))?;
-        writeln!(f, "Requirement({})", self.name)?;
+        writeln!(
+            f,
+            "{}",
+            options.messages.format("requirement.label", &[&self.name])
+        )?;
+
+        if let Some(auditor) = self.auditor().message_key() {
+            let m = &options.messages;
+            // the department is who audits by default, so it goes unnamed
+            let line = match self.auditor() {
+                AuditedBy::Department => m.get("requirement.audited").to_string(),
+                _ => m.format("requirement.audited-by", &[&m.get(auditor)]),
+            };
 
-        if self.is_audited {
             write!(f, "{}", " ".repeat(indent * 4))?;
-            writeln!(f, "is manually audited")?;
+            writeln!(f, "{}", line)?;
+        }
+
+        if self.is_contract {
+            write!(f, "{}", " ".repeat(indent * 4))?;
+            writeln!(f, "{}", options.messages.get("requirement.contract"))?;
         }
 
         if let Some(result) = &self.result {
@@ -60,17 +158,36 @@ impl ToProse for Requirement {
     }
 }
 
-use crate::to_record::{Record, RecordOptions, ToRecord};
+use crate::to_record::{Cell, Record, RecordOptions, RecordStatus, ToRecord};
 impl ToRecord for Requirement {
     fn get_row(&self, student: &Student, options: &RecordOptions, is_waived: bool) -> Vec<Record> {
         if self.path == &["$", "%Common Requirements"] {
             return vec![];
         }
 
+        let _span = tracing::trace_span!("requirement", name = %self.name).entered();
+
         let is_waived = is_waived || self.status.is_waived();
 
         let mut row = vec![];
 
+        // audited requirements usually have no result, so they'd otherwise have no column at all
+        if let (Some(auditor), None) = (self.auditor().message_key(), &self.result) {
+            row.push(Record {
+                title: self.name.clone(),
+                subtitle: None,
+                status: if is_waived {
+                    RecordStatus::Waived
+                } else {
+                    self.status
+                },
+                content: vec![Cell::Text(options.messages.format(
+                    "requirement.audited-cell",
+                    &[&options.messages.get(auditor)],
+                ))],
+            });
+        }
+
         if let Some(result) = &self.result {
             row.extend(
                 result
@@ -80,16 +197,44 @@ impl ToRecord for Requirement {
             );
         }
 
+        let status = if is_waived {
+            RecordStatus::Waived
+        } else {
+            self.status
+        };
+
+        if options.requirement_status {
+            let text = if status.is_waived() {
+                "waived"
+            } else if status.is_passing() {
+                "pass"
+            } else {
+                "fail"
+            };
+
+            row.push(self.summary_record("status", status, text.to_string()));
+        }
+
+        if options.requirement_rank {
+            row.push(self.summary_record("rank", status, self.fractional_rank()));
+        }
+
+        if options.requirement_claims {
+            let claimed = match &self.result {
+                Some(result) => result.claimed_clbids().len(),
+                None => 0,
+            };
+
+            row.push(self.summary_record("claimed courses", status, claimed.to_string()));
+        }
+
         row
     }
 
     fn get_requirements(&self) -> Vec<String> {
         let mut initial = vec![self.name.clone()];
-        match &self.result {
-            Some(r) => {
-                initial.extend(r.get_requirements().into_iter());
-            }
-            None => {}
+        if let Some(r) = &self.result {
+            initial.extend(r.get_requirements());
         }
         initial
     }
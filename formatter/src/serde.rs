@@ -4,3 +4,10 @@ pub fn empty_str_as_none<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Strin
     let o: Option<String> = Option::deserialize(d)?;
     Ok(o.filter(|s| !s.is_empty()))
 }
+
+pub fn null_as_default<'de, D: Deserializer<'de>, T: Deserialize<'de> + Default>(
+    d: D,
+) -> Result<T, D::Error> {
+    let o: Option<T> = Option::deserialize(d)?;
+    Ok(o.unwrap_or_default())
+}
@@ -1,5 +1,6 @@
 use crate::path::Path;
 use crate::rule::RuleStatus;
+use crate::term::{ParseTermError, Term};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 
@@ -24,6 +25,8 @@ pub struct Student {
     pub performances: Vec<Performance>,
     // pub proficiencies: StudentProficiencies,
     pub stnum: String,
+    #[serde(default)]
+    pub advisor: Option<Advisor>,
     // pub templates: BTreeMap<String, String>, // todo: type this accurately
 }
 
@@ -38,6 +41,10 @@ impl Student {
         }
     }
 
+    pub fn parsed_current_term(&self) -> Option<Term> {
+        self.current_term.as_ref().and_then(|t| t.parse().ok())
+    }
+
     pub fn emphases(&self) -> Vec<String> {
         self.areas
             .iter()
@@ -61,18 +68,27 @@ pub enum StudentClassification {
     NC,
 }
 
-impl ToString for StudentClassification {
-    fn to_string(&self) -> String {
-        match &self {
-            StudentClassification::SR => String::from("SR"),
-            StudentClassification::JR => String::from("JR"),
-            StudentClassification::SO => String::from("SO"),
-            StudentClassification::FY => String::from("FY"),
-            StudentClassification::NC => String::from("NC"),
-        }
+impl std::fmt::Display for StudentClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let output = match &self {
+            StudentClassification::SR => "SR",
+            StudentClassification::JR => "JR",
+            StudentClassification::SO => "SO",
+            StudentClassification::FY => "FY",
+            StudentClassification::NC => "NC",
+        };
+
+        f.write_str(output)
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Advisor {
+    pub name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StudentOrganization {
     pub dept: String,
@@ -216,14 +232,14 @@ impl Course {
 
     pub fn semi_verbose(&self) -> String {
         if self.institution_short == "STOLAF" {
-            format!("{}", self.course_with_term())
+            self.course_with_term()
         } else {
             format!("[{}] {}", self.institution_short, self.course_with_term())
         }
     }
 
     pub fn course_with_term(&self) -> String {
-        let label = if self.number == "" {
+        let label = if self.number.is_empty() {
             format!("\"{}\"", self.name,)
         } else {
             let suffix = match self.sub_type.as_str() {
@@ -248,6 +264,10 @@ impl Course {
         format!("{}-{}", self.year, self.term)
     }
 
+    pub fn parsed_term(&self) -> Result<Term, ParseTermError> {
+        Term::from_parts(&self.year, &self.term)
+    }
+
     pub fn is_in_progress(&self) -> bool {
         self.flag_in_progress
     }
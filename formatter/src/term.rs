@@ -0,0 +1,129 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Semester {
+    Fall,
+    Interim,
+    Spring,
+    SummerOne,
+    SummerTwo,
+}
+
+impl Semester {
+    pub fn from_number(n: u8) -> Option<Semester> {
+        match n {
+            1 => Some(Semester::Fall),
+            2 => Some(Semester::Interim),
+            3 => Some(Semester::Spring),
+            4 => Some(Semester::SummerOne),
+            5 => Some(Semester::SummerTwo),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Semester::Fall => 1,
+            Semester::Interim => 2,
+            Semester::Spring => 3,
+            Semester::SummerOne => 4,
+            Semester::SummerTwo => 5,
+        }
+    }
+}
+
+impl Display for Semester {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            Semester::Fall => "Fall",
+            Semester::Interim => "Interim",
+            Semester::Spring => "Spring",
+            Semester::SummerOne => "Summer I",
+            Semester::SummerTwo => "Summer II",
+        };
+
+        f.write_str(output)
+    }
+}
+
+/// A term, as an academic year plus a semester.
+///
+/// Academic years are named by the calendar year they start in, so "2014-3"
+/// is the spring semester of the 2014-15 year, and displays as "Spring 2015".
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Term {
+    year: u16,
+    semester: Semester,
+}
+
+impl Term {
+    pub fn new(year: u16, semester: Semester) -> Term {
+        Term { year, semester }
+    }
+
+    pub fn from_parts(year: &str, term: &str) -> Result<Term, ParseTermError> {
+        let error = || ParseTermError(format!("{}-{}", year, term));
+
+        let year = year.parse::<u16>().map_err(|_| error())?;
+        let semester = term
+            .parse::<u8>()
+            .ok()
+            .and_then(Semester::from_number)
+            .ok_or_else(error)?;
+
+        Ok(Term { year, semester })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn semester(&self) -> Semester {
+        self.semester
+    }
+
+    pub fn calendar_year(&self) -> u16 {
+        match self.semester {
+            Semester::Fall => self.year,
+            _ => self.year + 1,
+        }
+    }
+}
+
+impl FromStr for Term {
+    type Err = ParseTermError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // accepts both "2014-3" (as in area specs) and "20143" (as in a student's current_term)
+        if let Some((year, term)) = s.split_once('-') {
+            return Term::from_parts(year, term);
+        }
+
+        if s.len() == 5 && s.is_char_boundary(4) {
+            let (year, term) = s.split_at(4);
+            return Term::from_parts(year, term);
+        }
+
+        Err(ParseTermError(s.to_string()))
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.semester, self.calendar_year())
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseTermError(String);
+
+impl Display for ParseTermError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not parse {:?} as a term", self.0)
+    }
+}
+
+impl std::error::Error for ParseTermError {}
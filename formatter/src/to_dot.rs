@@ -0,0 +1,115 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::messages::Catalog;
+use crate::rule::{Rule, RuleStatus};
+
+/// Renders the requirement tree of an area as a Graphviz digraph
+pub struct DotContext<'a> {
+    pub result: &'a AreaOfStudy,
+    pub messages: &'a Catalog,
+}
+
+impl<'a> std::fmt::Display for DotContext<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph area {{")?;
+        writeln!(f, "    rankdir=LR;")?;
+        writeln!(f, "    node [shape=box, style=filled];")?;
+
+        write_node(f, 0, &self.result.name, &self.result.status)?;
+
+        let mut next_id = 1;
+        write_rule(f, &self.result.result, 0, &mut next_id, self.messages)?;
+
+        writeln!(f, "}}")
+    }
+}
+
+fn fill_color(status: &RuleStatus) -> &'static str {
+    match status {
+        RuleStatus::Done | RuleStatus::Waived | RuleStatus::PendingCurrent => "palegreen",
+        RuleStatus::PendingRegistered | RuleStatus::PendingApproval => "khaki",
+        RuleStatus::NeedsMoreItems | RuleStatus::Empty | RuleStatus::FailedInvariant => "lightpink",
+    }
+}
+
+fn write_node(
+    f: &mut std::fmt::Formatter<'_>,
+    id: usize,
+    label: &str,
+    status: &RuleStatus,
+) -> std::fmt::Result {
+    writeln!(
+        f,
+        "    n{} [label=\"{}\", fillcolor={}];",
+        id,
+        label.replace('\\', "\\\\").replace('"', "\\\""),
+        fill_color(status)
+    )
+}
+
+fn write_rule(
+    f: &mut std::fmt::Formatter<'_>,
+    rule: &Rule,
+    parent: usize,
+    next_id: &mut usize,
+    messages: &Catalog,
+) -> std::fmt::Result {
+    let id = *next_id;
+    *next_id += 1;
+
+    let (label, status, children): (String, &RuleStatus, Vec<&Rule>) = match rule {
+        Rule::Count(r) => {
+            let size = r.items.len();
+            let label = if r.at_most {
+                messages.format("count.at-most", &[&r.count, &size])
+            } else if r.count == 1 && size == 2 {
+                messages.get("graph.either").to_string()
+            } else if r.count == size {
+                messages.format("graph.all", &[&size])
+            } else {
+                messages.format("graph.some", &[&r.count, &size])
+            };
+            (
+                label,
+                &r.status,
+                r.items.iter().map(|i| i.as_ref()).collect(),
+            )
+        }
+        Rule::Course(r) => {
+            let label = r.label();
+            (label, &r.status, vec![])
+        }
+        Rule::Proficiency(r) => (
+            messages.format("graph.proficiency", &[&r.proficiency]),
+            &r.status,
+            vec![],
+        ),
+        Rule::Requirement(r) => (
+            r.name.clone(),
+            &r.status,
+            r.result.iter().map(|r| r.as_ref()).collect(),
+        ),
+        Rule::Query(r) => (
+            messages.format("graph.given", &[&r.source]),
+            &r.status,
+            vec![],
+        ),
+        Rule::Conditional(r) => {
+            let mut children = vec![r.when_true.as_ref()];
+            children.extend(r.when_false.iter().map(|r| r.as_ref()));
+            (
+                messages.format("graph.if", &[&r.condition]),
+                &r.status,
+                children,
+            )
+        }
+    };
+
+    write_node(f, id, &label, status)?;
+    writeln!(f, "    n{} -> n{};", parent, id)?;
+
+    for child in children {
+        write_rule(f, child, id, next_id, messages)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,311 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::messages::Catalog;
+use crate::rule::{Rule, RuleStatus};
+use crate::student::{Course, Student};
+
+/// Renders a single student's area result as a standalone LaTeX document
+pub struct LatexContext<'a, 'b> {
+    pub result: &'b AreaOfStudy,
+    pub student: &'a Student,
+    pub messages: &'b Catalog,
+}
+
+impl<'a, 'b> std::fmt::Display for LatexContext<'a, 'b> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, r"\documentclass[11pt]{{article}}")?;
+        writeln!(f, r"\usepackage[margin=0.75in]{{geometry}}")?;
+        writeln!(f, r"\usepackage[utf8]{{inputenc}}")?;
+        writeln!(f, r"\usepackage{{amssymb}}")?;
+        writeln!(f, r"\begin{{document}}")?;
+
+        writeln!(f, r"\section*{{{}}}", escape(&self.result.name))?;
+        writeln!(
+            f,
+            r"{} ({}) \hfill {} \\",
+            escape(&self.student.name),
+            escape(&self.student.stnum),
+            escape(&self.result.code),
+        )?;
+        writeln!(
+            f,
+            r"{} \hfill {}",
+            escape(&self.messages.format(
+                "latex.status",
+                &[&status_label(&self.result.status, self.messages)]
+            )),
+            escape(&self.messages.format("latex.gpa", &[&self.result.gpa])),
+        )?;
+        writeln!(f)?;
+
+        write_sections(f, &self.result.result, self.student, self.messages)?;
+
+        writeln!(f, r"\end{{document}}")
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str(r"\textbackslash{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str(r"\textasciitilde{}"),
+            '^' => out.push_str(r"\textasciicircum{}"),
+            // the prose and labels use a few symbols that pdflatex has no glyph for in utf8 input
+            '…' => out.push_str(r"\ldots{}"),
+            '→' => out.push_str(r"$\rightarrow$"),
+            '✓' => out.push_str(r"$\checkmark$"),
+            '✗' => out.push_str(r"$\times$"),
+            '≥' => out.push_str(r"$\geq$"),
+            '≤' => out.push_str(r"$\leq$"),
+            '∈' => out.push_str(r"$\in$"),
+            '∉' => out.push_str(r"$\notin$"),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+fn status_label<'a>(status: &RuleStatus, messages: &'a Catalog) -> &'a str {
+    messages.get(match status {
+        RuleStatus::Done => "latex.status.done",
+        RuleStatus::Waived => "latex.status.waived",
+        RuleStatus::PendingCurrent => "latex.status.pending-current",
+        RuleStatus::PendingRegistered => "latex.status.pending-registered",
+        RuleStatus::PendingApproval => "latex.status.pending-approval",
+        RuleStatus::NeedsMoreItems => "latex.status.needs-more-items",
+        RuleStatus::Empty => "latex.status.empty",
+        RuleStatus::FailedInvariant => "latex.status.failed-invariant",
+    })
+}
+
+fn checkbox(status: &RuleStatus) -> &'static str {
+    if status.is_passing() {
+        r"$\boxtimes$"
+    } else {
+        r"$\square$"
+    }
+}
+
+fn waiver_note(rule: &Rule, messages: &Catalog) -> String {
+    if !rule.status().is_waived() {
+        return String::new();
+    }
+
+    format!(
+        r" \textit{{{}}}\protect\footnote{{{}}}",
+        escape(messages.get("latex.waived")),
+        escape(messages.get(waiver_reason(rule))),
+    )
+}
+
+// the engine marks a rule waived when an exception overrides it, when an exception inserts the
+// course it needed, when it's optional, or when everything beneath it was waived
+fn waiver_reason(rule: &Rule) -> &'static str {
+    match rule {
+        Rule::Count(r) if r.overridden => "latex.waiver.override",
+        Rule::Requirement(r) if r.overridden => "latex.waiver.override",
+        Rule::Query(r) if r.overridden => "latex.waiver.override",
+        Rule::Proficiency(r) if r.overridden => "latex.waiver.override",
+        Rule::Course(r) if r.overridden => "latex.waiver.override",
+        Rule::Course(r) if r.inserted => "latex.waiver.inserted",
+        Rule::Course(r) if r.optional => "latex.waiver.optional",
+        Rule::Query(_) => "latex.waiver.assertions",
+        _ => "latex.waiver.items",
+    }
+}
+
+fn write_courses(f: &mut std::fmt::Formatter<'_>, courses: &[&Course]) -> std::fmt::Result {
+    if courses.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f)?;
+    writeln!(f, r"\begin{{tabular}}{{llll}}")?;
+    for c in courses {
+        writeln!(
+            f,
+            r"{} & {} & {} & {} \\",
+            escape(&c.course),
+            escape(&c.name),
+            escape(&c.parsed_term().map_or(String::new(), |t| t.to_string())),
+            escape(&c.credits),
+        )?;
+    }
+    writeln!(f, r"\end{{tabular}}")
+}
+
+// conditionals only contribute the branch that applied
+fn active(rule: &Rule) -> Option<&Rule> {
+    match rule {
+        Rule::Conditional(r) => r.active_branch().and_then(active),
+        _ => Some(rule),
+    }
+}
+
+fn write_sections(
+    f: &mut std::fmt::Formatter<'_>,
+    rule: &Rule,
+    student: &Student,
+    messages: &Catalog,
+) -> std::fmt::Result {
+    match active(rule) {
+        Some(Rule::Count(r)) if r.all_items_are_requirements() => {
+            for item in &r.items {
+                write_sections(f, item, student, messages)?;
+            }
+            Ok(())
+        }
+        Some(section @ Rule::Requirement(r)) => {
+            writeln!(
+                f,
+                r"\subsection*{{{} {}{}}}",
+                checkbox(&r.status),
+                escape(&r.name),
+                waiver_note(section, messages),
+            )?;
+
+            if let Some(message) = &r.message {
+                writeln!(f, r"\textit{{{}}}", escape(message))?;
+                writeln!(f)?;
+            }
+
+            match &r.result {
+                Some(result) => write_list(f, &[result.as_ref()], student, messages),
+                None => Ok(()),
+            }
+        }
+        Some(rule) => write_list(f, &[rule], student, messages),
+        None => Ok(()),
+    }
+}
+
+fn write_list(
+    f: &mut std::fmt::Formatter<'_>,
+    rules: &[&Rule],
+    student: &Student,
+    messages: &Catalog,
+) -> std::fmt::Result {
+    let rules = rules.iter().filter_map(|r| active(r)).collect::<Vec<_>>();
+
+    // latex refuses to render an itemize without any items
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f, r"\begin{{itemize}}")?;
+    for rule in rules {
+        write_item(f, rule, student, messages)?;
+    }
+    writeln!(f, r"\end{{itemize}}")
+}
+
+fn write_item(
+    f: &mut std::fmt::Formatter<'_>,
+    rule: &Rule,
+    student: &Student,
+    messages: &Catalog,
+) -> std::fmt::Result {
+    match rule {
+        Rule::Count(r) => {
+            writeln!(
+                f,
+                r"\item {} {}{}",
+                checkbox(&r.status),
+                escape(&messages.format(
+                    if r.at_most {
+                        "count.at-most"
+                    } else {
+                        "count.at-least"
+                    },
+                    &[&r.count, &r.items.len()]
+                )),
+                waiver_note(rule, messages),
+            )?;
+
+            let items = r.items.iter().map(|i| i.as_ref()).collect::<Vec<_>>();
+            write_list(f, &items, student, messages)
+        }
+        Rule::Requirement(r) => {
+            writeln!(
+                f,
+                r"\item {} \textbf{{{}}}{}",
+                checkbox(&r.status),
+                escape(&r.name),
+                waiver_note(rule, messages),
+            )?;
+
+            if let Some(message) = &r.message {
+                writeln!(f, r"\\ \textit{{{}}}", escape(message))?;
+            }
+
+            match &r.result {
+                Some(result) => write_list(f, &[result.as_ref()], student, messages),
+                None => Ok(()),
+            }
+        }
+        Rule::Course(r) => {
+            let matched = r
+                .claims
+                .first()
+                .and_then(|claim| student.get_class_by_clbid(&claim.clbid));
+
+            write!(
+                f,
+                r"\item {} {}",
+                checkbox(&r.status),
+                escape(&r.describe(messages))
+            )?;
+            if let Some(course) = matched {
+                write!(f, r" \hfill {}", escape(&course.semi_verbose()))?;
+            }
+            writeln!(f, "{}", waiver_note(rule, messages))
+        }
+        Rule::Proficiency(r) => writeln!(
+            f,
+            r"\item {} {}{}",
+            checkbox(&r.status),
+            escape(&messages.format("proficiency.label", &[&r.proficiency])),
+            waiver_note(rule, messages),
+        ),
+        Rule::Query(r) => {
+            writeln!(
+                f,
+                r"\item {} {}{}",
+                checkbox(&r.status),
+                escape(&messages.format("query.given", &[&r.source])),
+                waiver_note(rule, messages),
+            )?;
+
+            for a in r.assertions.iter().filter_map(|a| a.active_rule()) {
+                let evidence = a.evidence(student);
+                writeln!(
+                    f,
+                    r"\\ {} {}",
+                    checkbox(&a.status),
+                    escape(&messages.format(
+                        "assertion.needs-has",
+                        &[&a.key, &evidence.expected, &evidence.observed]
+                    )),
+                )?;
+            }
+
+            let courses = r
+                .claims
+                .iter()
+                .filter_map(|c| student.get_class_by_clbid(&c.clbid))
+                .collect::<Vec<_>>();
+
+            write_courses(f, &courses)
+        }
+        Rule::Conditional(_) => match active(rule) {
+            Some(rule) => write_item(f, rule, student, messages),
+            None => Ok(()),
+        },
+    }
+}
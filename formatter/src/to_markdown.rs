@@ -0,0 +1,184 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::assertion::{Assertion, AssertionRule};
+use crate::messages::Catalog;
+use crate::rule::{Rule, RuleStatus};
+use crate::student::Student;
+
+pub trait ToMarkdown {
+    fn to_markdown(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        student: &Student,
+        messages: &Catalog,
+        indent: usize,
+    ) -> std::fmt::Result;
+}
+
+pub struct MarkdownContext<'a, 'b> {
+    pub result: &'b AreaOfStudy,
+    pub student: &'a Student,
+    pub messages: &'b Catalog,
+}
+
+impl<'a, 'b> std::fmt::Display for MarkdownContext<'a, 'b> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.result.to_markdown(f, self.student, self.messages, 0)
+    }
+}
+
+fn write_item(
+    f: &mut std::fmt::Formatter<'_>,
+    status: &RuleStatus,
+    indent: usize,
+    label: &str,
+) -> std::fmt::Result {
+    let checkbox = if status.is_passing() { "[x]" } else { "[ ]" };
+    writeln!(f, "{}- {} {}", "  ".repeat(indent), checkbox, label)
+}
+
+impl ToMarkdown for AreaOfStudy {
+    fn to_markdown(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        student: &Student,
+        messages: &Catalog,
+        indent: usize,
+    ) -> std::fmt::Result {
+        writeln!(f, "# {}", self.name)?;
+        writeln!(f)?;
+        writeln!(f, "{} ({}), {}", student.name, student.stnum, self.code)?;
+        writeln!(f)?;
+
+        self.result.to_markdown(f, student, messages, indent)
+    }
+}
+
+impl ToMarkdown for Rule {
+    fn to_markdown(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        student: &Student,
+        messages: &Catalog,
+        indent: usize,
+    ) -> std::fmt::Result {
+        match self {
+            Rule::Count(r) => {
+                let size = r.items.len();
+                let label = if r.at_most {
+                    messages.format("count.at-most", &[&r.count, &size])
+                } else if r.count == size {
+                    messages.format("count.all", &[&size])
+                } else {
+                    messages.format("count.at-least", &[&r.count, &size])
+                };
+
+                write_item(f, &r.status, indent, &label)?;
+
+                for item in &r.items {
+                    item.to_markdown(f, student, messages, indent + 1)?;
+                }
+
+                Ok(())
+            }
+            Rule::Course(r) => {
+                let label = r.describe(messages);
+
+                let matched = r
+                    .claims
+                    .first()
+                    .and_then(|claim| student.get_class_by_clbid(&claim.clbid));
+
+                match matched {
+                    Some(course) => {
+                        let label = format!("{} ({})", label, course.semi_verbose());
+                        write_item(f, &r.status, indent, &label)
+                    }
+                    None => write_item(f, &r.status, indent, &label),
+                }
+            }
+            Rule::Proficiency(r) => {
+                let label = messages.format("proficiency.label", &[&r.proficiency]);
+                write_item(f, &r.status, indent, &label)
+            }
+            Rule::Requirement(r) => {
+                let label = format!("**{}**", r.name);
+                write_item(f, &r.status, indent, &label)?;
+
+                if let Some(result) = &r.result {
+                    result.to_markdown(f, student, messages, indent + 1)?;
+                }
+
+                Ok(())
+            }
+            Rule::Query(r) => {
+                let label = messages.format("query.given", &[&r.source]);
+                write_item(f, &r.status, indent, &label)?;
+
+                for a in &r.assertions {
+                    a.to_markdown(f, student, messages, indent + 1)?;
+                }
+
+                Ok(())
+            }
+            Rule::Conditional(r) => match r.active_branch() {
+                Some(branch) => branch.to_markdown(f, student, messages, indent),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+impl ToMarkdown for Assertion {
+    fn to_markdown(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        student: &Student,
+        messages: &Catalog,
+        indent: usize,
+    ) -> std::fmt::Result {
+        match self {
+            Assertion::Rule(a) => a.to_markdown(f, student, messages, indent),
+            Assertion::Conditional(a) => match a.condition.result() {
+                Some(true) => a.when_true.to_markdown(f, student, messages, indent),
+                Some(false) => match &a.when_false {
+                    Some(when_false) => when_false.to_markdown(f, student, messages, indent),
+                    None => Ok(()),
+                },
+                None => Ok(()),
+            },
+            Assertion::DynamicConditional(a) => {
+                a.when_true.to_markdown(f, student, messages, indent)
+            }
+        }
+    }
+}
+
+impl ToMarkdown for AssertionRule {
+    fn to_markdown(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        student: &Student,
+        messages: &Catalog,
+        indent: usize,
+    ) -> std::fmt::Result {
+        let evidence = self.evidence(student);
+
+        let label = messages.format(
+            "assertion.compare-has",
+            &[
+                &self.key,
+                &self.operator,
+                &evidence.expected,
+                &evidence.observed,
+            ],
+        );
+        write_item(f, &self.status, indent, &label)?;
+
+        for course in evidence.courses {
+            write!(f, "{}", "  ".repeat(indent + 1))?;
+            writeln!(f, "- {}", course.semi_verbose())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 use crate::area_of_study::AreaOfStudy;
+use crate::messages::Catalog;
+use crate::rule::RuleStatus;
 use crate::student::Student;
+use std::io::IsTerminal;
 
 pub trait ToProse {
     fn to_prose(
@@ -14,6 +17,130 @@ pub trait ToProse {
 pub struct ProseOptions {
     pub show_paths: bool,
     pub show_ranks: bool,
+    /// List the unfinished requirements, closest to completion first, before the full audit
+    pub show_summary: bool,
+    pub color: ColorMode,
+    pub symbols: SymbolTheme,
+    pub messages: Catalog,
+    /// Wrap lines longer than this many columns, with a hanging indent
+    pub width: Option<usize>,
+    /// Replace non-ASCII glyphs and punctuation with plain ASCII
+    pub ascii: bool,
+}
+
+impl Default for ProseOptions {
+    fn default() -> Self {
+        ProseOptions {
+            show_paths: false,
+            show_ranks: false,
+            show_summary: false,
+            color: ColorMode::Never,
+            symbols: SymbolTheme::plain(),
+            messages: Catalog::english(),
+            width: None,
+            ascii: false,
+        }
+    }
+}
+
+impl ProseOptions {
+    pub fn paint_status(&self, status: &RuleStatus) -> String {
+        let glyph = self.symbols.for_status(status);
+        let label = self.messages.get(status.message_key());
+
+        if glyph.is_empty() {
+            self.paint(label, status)
+        } else {
+            self.paint(&format!("{} {}", glyph, label), status)
+        }
+    }
+
+    pub fn paint(&self, text: &str, status: &RuleStatus) -> String {
+        if !self.color.is_enabled() {
+            return text.to_string();
+        }
+
+        let code = match status {
+            RuleStatus::Done | RuleStatus::PendingCurrent => "32",
+            RuleStatus::Waived => "36",
+            RuleStatus::PendingRegistered | RuleStatus::PendingApproval => "33",
+            RuleStatus::NeedsMoreItems | RuleStatus::Empty | RuleStatus::FailedInvariant => "31",
+        };
+
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorMode::Auto),
+            "always" => Ok(ColorMode::Always),
+            "never" => Ok(ColorMode::Never),
+            _ => Err(format!("expected one of auto, always, never; got {:?}", s)),
+        }
+    }
+}
+
+/// The glyphs printed in front of a rule's status
+#[derive(Debug, Clone)]
+pub struct SymbolTheme {
+    pub passing: String,
+    pub pending: String,
+    pub waived: String,
+    pub failing: String,
+}
+
+impl SymbolTheme {
+    pub fn plain() -> SymbolTheme {
+        SymbolTheme {
+            passing: String::new(),
+            pending: String::new(),
+            waived: String::new(),
+            failing: String::new(),
+        }
+    }
+
+    pub fn unicode() -> SymbolTheme {
+        SymbolTheme {
+            passing: "✓".to_string(),
+            pending: "…".to_string(),
+            waived: "~".to_string(),
+            failing: "✗".to_string(),
+        }
+    }
+
+    pub fn for_status(&self, status: &RuleStatus) -> &str {
+        match status {
+            RuleStatus::Done | RuleStatus::PendingCurrent => &self.passing,
+            RuleStatus::Waived => &self.waived,
+            RuleStatus::PendingRegistered | RuleStatus::PendingApproval => &self.pending,
+            RuleStatus::NeedsMoreItems | RuleStatus::Empty | RuleStatus::FailedInvariant => {
+                &self.failing
+            }
+        }
+    }
 }
 
 pub struct ProseContext<'a, 'b, 'c> {
@@ -22,8 +149,108 @@ pub struct ProseContext<'a, 'b, 'c> {
     pub options: &'b ProseOptions,
 }
 
+struct RawProse<'a, 'b, 'c>(&'a ProseContext<'a, 'b, 'c>);
+
+impl<'a, 'b, 'c> std::fmt::Display for RawProse<'a, 'b, 'c> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.result.to_prose(f, self.0.student, self.0.options, 0)
+    }
+}
+
 impl<'a, 'b, 'c> std::fmt::Display for ProseContext<'a, 'b, 'c> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.result.to_prose(f, self.student, self.options, 0)
+        if self.options.width.is_none() && !self.options.ascii {
+            return self.result.to_prose(f, self.student, self.options, 0);
+        }
+
+        let mut text = RawProse(self).to_string();
+
+        if self.options.ascii {
+            text = to_ascii(&text);
+        }
+
+        match self.options.width {
+            Some(width) => {
+                for line in text.lines() {
+                    wrap_line(f, line, width)?;
+                }
+                Ok(())
+            }
+            None => write!(f, "{}", text),
+        }
     }
 }
+
+fn to_ascii(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '✓' => out.push_str("[x]"),
+            '✗' => out.push_str("[ ]"),
+            '…' => out.push_str("..."),
+            '→' => out.push_str("->"),
+            '—' | '–' => out.push('-'),
+            '‘' | '’' => out.push('\''),
+            '“' | '”' => out.push('"'),
+            '≥' => out.push_str(">="),
+            '≤' => out.push_str("<="),
+            '∈' => out.push_str("in"),
+            '∉' => out.push_str("not in"),
+            c if c.is_ascii() => out.push(c),
+            _ => out.push('?'),
+        }
+    }
+
+    out
+}
+
+/// The printed width of some text, not counting ANSI color escapes
+fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+
+    for c in text.chars() {
+        if in_escape {
+            in_escape = c != 'm';
+        } else if c == '\x1b' {
+            in_escape = true;
+        } else {
+            width += 1;
+        }
+    }
+
+    width
+}
+
+/// Breaks a line at spaces so that it fits in `width` columns; continuation
+/// lines are indented one level past the line's own indentation
+fn wrap_line(f: &mut std::fmt::Formatter<'_>, line: &str, width: usize) -> std::fmt::Result {
+    let body = line.trim_start_matches(' ');
+    let indent = line.len() - body.len();
+    let hanging = " ".repeat(indent + 4);
+
+    write!(f, "{}", &line[..indent])?;
+    let mut column = indent;
+    let mut at_line_start = true;
+
+    for word in body.split(' ') {
+        let word_width = display_width(word);
+
+        if !at_line_start && column + 1 + word_width > width {
+            writeln!(f)?;
+            write!(f, "{}{}", hanging, word)?;
+            column = hanging.len() + word_width;
+        } else if at_line_start {
+            write!(f, "{}", word)?;
+            column += word_width;
+        } else {
+            write!(f, " {}", word)?;
+            column += 1 + word_width;
+        }
+
+        at_line_start = false;
+    }
+
+    writeln!(f)
+}
@@ -1,4 +1,6 @@
+use crate::messages::Catalog;
 use crate::student;
+use crate::student::ClassLabId;
 
 #[derive(Debug, Clone)]
 pub struct Record {
@@ -32,11 +34,16 @@ impl Record {
     pub fn status_class(&self) -> &str {
         self.status.as_classname()
     }
+
+    pub fn is_emphasis(&self) -> bool {
+        crate::rule::requirement::is_emphasis_name(&self.title)
+    }
 }
 
 pub type RecordStatus = crate::rule::RuleStatus;
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum Cell {
     Text(String),
     SingleCourse(student::Course),
@@ -63,6 +70,25 @@ impl Cell {
     }
 }
 
+impl Cell {
+    /// Lists courses as text, marking the ones that were inserted by an exception
+    pub(crate) fn marked_courses(courses: &[&student::Course], inserted: &[ClassLabId]) -> Cell {
+        Cell::Text(
+            courses
+                .iter()
+                .map(|c| {
+                    if inserted.contains(&c.clbid) {
+                        format!("{} [ins]", c.semi_verbose())
+                    } else {
+                        c.semi_verbose()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("<br>"),
+        )
+    }
+}
+
 pub trait ToRecord {
     fn get_row(
         &self,
@@ -74,10 +100,11 @@ pub trait ToRecord {
     fn get_requirements(&self) -> Vec<String>;
 
     fn emphasis_requirement_names(&self) -> Vec<String> {
+        use crate::rule::requirement::is_emphasis_name;
         use std::collections::BTreeSet;
         self.get_requirements()
             .iter()
-            .filter(|e| e.starts_with("Emphasis: "))
+            .filter(|e| is_emphasis_name(e))
             .map(|name| String::from(name.split(" → ").take(1).last().unwrap()))
             .collect::<BTreeSet<_>>()
             .into_iter()
@@ -85,4 +112,49 @@ pub trait ToRecord {
     }
 }
 
-pub struct RecordOptions {}
+#[derive(Debug, Clone, Default)]
+pub struct RecordOptions {
+    /// Column titles to pin to the front, in order; anything unlisted follows after
+    pub column_order: Vec<String>,
+    /// Add a pass/fail/waived column for each requirement
+    pub requirement_status: bool,
+    /// Add a column with each requirement's rank as a fraction of its max rank
+    pub requirement_rank: bool,
+    /// Add a column with the number of courses claimed under each requirement
+    pub requirement_claims: bool,
+    /// Mark courses inserted by an exception with "[ins]", and waived cells with "[waived]"
+    pub mark_exceptions: bool,
+    /// The text for generated column titles
+    pub messages: Catalog,
+}
+
+impl RecordOptions {
+    pub fn arrange(&self, records: Vec<Record>) -> Vec<Record> {
+        if self.column_order.is_empty() {
+            return records;
+        }
+
+        let mut arranged = Vec::with_capacity(records.len());
+        let mut remaining = records;
+
+        for title in &self.column_order {
+            let (matching, rest): (Vec<Record>, Vec<Record>) =
+                remaining.into_iter().partition(|r| &r.title == title);
+            remaining = rest;
+
+            if matching.is_empty() {
+                arranged.push(Record {
+                    title: title.clone(),
+                    subtitle: None,
+                    status: RecordStatus::Empty,
+                    content: vec![],
+                });
+            } else {
+                arranged.extend(matching);
+            }
+        }
+
+        arranged.extend(remaining);
+        arranged
+    }
+}
@@ -0,0 +1,80 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::rule::requirement::Requirement;
+use crate::rule::{Rule, RuleStatus};
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+/// A compact overview of an area result, for places that don't need the full document
+#[derive(Serialize, Debug, Clone)]
+pub struct AreaSummary {
+    pub code: String,
+    pub name: String,
+    pub status: RuleStatus,
+    pub ok: bool,
+    pub percent_complete: Option<Decimal>,
+    pub unsatisfied_requirements: Vec<String>,
+    pub claimed_courses: usize,
+}
+
+impl AreaOfStudy {
+    pub fn to_summary(&self) -> AreaSummary {
+        let unsatisfied = self
+            .unsatisfied_requirements()
+            .iter()
+            .map(|r| r.name.clone())
+            .collect();
+
+        AreaSummary {
+            code: self.code.clone(),
+            name: self.name.clone(),
+            status: self.status,
+            ok: self.ok,
+            percent_complete: percent(&self.rank, &self.max_rank),
+            unsatisfied_requirements: unsatisfied,
+            claimed_courses: self.result.claimed_clbids().len(),
+        }
+    }
+
+    /// Every requirement that isn't passing, skipping the untaken branch of conditionals
+    pub fn unsatisfied_requirements(&self) -> Vec<&Requirement> {
+        let mut found = Vec::new();
+        collect_unsatisfied(&self.result, &mut found);
+        found
+    }
+}
+
+pub(crate) fn percent(rank: &str, max_rank: &str) -> Option<Decimal> {
+    let rank = rank.parse::<Decimal>().ok()?;
+    let max_rank = max_rank.parse::<Decimal>().ok()?;
+
+    if max_rank == Decimal::new(0, 0) {
+        return None;
+    }
+
+    Some((rank / max_rank * Decimal::new(100, 0)).round_dp(1))
+}
+
+fn collect_unsatisfied<'a>(rule: &'a Rule, names: &mut Vec<&'a Requirement>) {
+    match rule {
+        Rule::Count(r) => {
+            for item in &r.items {
+                collect_unsatisfied(item, names);
+            }
+        }
+        Rule::Requirement(r) => {
+            if !r.status.is_passing() {
+                names.push(r);
+            }
+
+            if let Some(result) = &r.result {
+                collect_unsatisfied(result, names);
+            }
+        }
+        Rule::Conditional(r) => {
+            if let Some(branch) = r.active_branch() {
+                collect_unsatisfied(branch, names);
+            }
+        }
+        Rule::Course(_) | Rule::Proficiency(_) | Rule::Query(_) => {}
+    }
+}
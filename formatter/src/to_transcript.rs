@@ -0,0 +1,100 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::rule::Rule;
+use crate::student::{ClassLabId, Course, Student};
+use crate::term::Term;
+use std::collections::BTreeMap;
+
+/// Lists a student's courses term by term, noting which requirements claimed each one
+pub struct TranscriptContext<'a, 'b> {
+    pub result: &'b AreaOfStudy,
+    pub student: &'a Student,
+}
+
+fn collect_claims(rule: &Rule, requirement: &str, claims: &mut Vec<(ClassLabId, String)>) {
+    match rule {
+        Rule::Count(r) => {
+            for item in &r.items {
+                collect_claims(item, requirement, claims);
+            }
+        }
+        Rule::Requirement(r) => {
+            if let Some(result) = &r.result {
+                let name = if requirement.is_empty() {
+                    r.name.clone()
+                } else {
+                    format!("{} → {}", requirement, r.name)
+                };
+                collect_claims(result, &name, claims);
+            }
+        }
+        Rule::Conditional(r) => {
+            if let Some(branch) = r.active_branch() {
+                collect_claims(branch, requirement, claims);
+            }
+        }
+        Rule::Course(_) | Rule::Proficiency(_) | Rule::Query(_) => {
+            for clbid in rule.claimed_clbids() {
+                claims.push((clbid, requirement.to_string()));
+            }
+        }
+    }
+}
+
+impl<'a, 'b> std::fmt::Display for TranscriptContext<'a, 'b> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut claims = vec![];
+        collect_claims(&self.result.result, "", &mut claims);
+
+        let mut claimed_by: BTreeMap<ClassLabId, Vec<String>> = BTreeMap::new();
+        for (clbid, requirement) in claims {
+            if let Some(course) = self.student.get_class_by_clbid(&clbid) {
+                let requirement = if requirement.is_empty() {
+                    self.result.name.clone()
+                } else {
+                    requirement
+                };
+                claimed_by
+                    .entry(course.unique_id())
+                    .or_default()
+                    .push(requirement);
+            }
+        }
+
+        let mut terms: BTreeMap<Term, Vec<&Course>> = BTreeMap::new();
+        let mut unknown: Vec<&Course> = vec![];
+        for course in &self.student.courses {
+            match course.parsed_term() {
+                Ok(term) => terms.entry(term).or_default().push(course),
+                Err(_) => unknown.push(course),
+            }
+        }
+
+        writeln!(
+            f,
+            "{} ({}), {}",
+            self.student.name, self.student.stnum, self.result.name
+        )?;
+
+        let groups = terms
+            .iter()
+            .map(|(term, courses)| (term.to_string(), courses))
+            .chain(std::iter::once((String::from("Unknown term"), &unknown)))
+            .filter(|(_, courses)| !courses.is_empty());
+
+        for (heading, courses) in groups {
+            writeln!(f)?;
+            writeln!(f, "{}", heading)?;
+
+            for course in courses.iter() {
+                write!(f, "    {}", course.verbose())?;
+
+                match claimed_by.get(&course.unique_id()) {
+                    Some(requirements) => writeln!(f, " → {}", requirements.join("; "))?,
+                    None => writeln!(f)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::area_of_study::AreaOfStudy;
+use crate::student::{ClassLabId, Student};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// The course types the auditor knows how to handle. These are the values of `CourseType` in
+/// dp/data/course_enums.py; tests/test_course_types.py fails if the two lists drift apart.
+const COURSE_TYPES: &[&str] = &[
+    "SE", "IN", "SM", "CA", "IE", "OC", "OP", "CE", "AP", "PS", "AD", "GE", "OT", "TR", "PC",
+];
+
+/// Thresholds for the checks that depend on local policy
+#[derive(Debug, Clone, Default)]
+pub struct ValidateOptions {
+    /// Flag courses worth more than this many credits; without it, any non-negative amount is fine
+    pub max_credits: Option<Decimal>,
+}
+
+/// A problem with a student document that is likely to throw off an audit
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum Issue {
+    /// More than one course has the same clbid (and schedid)
+    #[serde(rename = "duplicate-clbid")]
+    DuplicateClbid { clbid: ClassLabId, count: usize },
+    /// An audit result claims a course that isn't on the student's transcript
+    #[serde(rename = "unknown-claim")]
+    UnknownClaim { area: String, clbid: ClassLabId },
+    #[serde(rename = "malformed-term")]
+    MalformedTerm {
+        course: String,
+        year: String,
+        term: String,
+    },
+    /// The credits are negative, over the `max_credits` option, or not a number at all
+    #[serde(rename = "credits-out-of-range")]
+    CreditsOutOfRange { course: String, credits: String },
+    #[serde(rename = "unknown-course-type")]
+    UnknownCourseType { course: String, course_type: String },
+    /// The student document could not be read, or is not a student document at all
+    #[serde(rename = "unreadable")]
+    Unreadable { message: String },
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::DuplicateClbid { clbid, count } => {
+                write!(f, "clbid {} is used by {} courses", clbid.clbid(), count)
+            }
+            Issue::UnknownClaim { area, clbid } => write!(
+                f,
+                "{} claims clbid {}, which is not on the transcript",
+                area,
+                clbid.clbid()
+            ),
+            Issue::MalformedTerm { course, year, term } => {
+                write!(f, "{} has a malformed term \"{}-{}\"", course, year, term)
+            }
+            Issue::CreditsOutOfRange { course, credits } => {
+                write!(f, "{} has out-of-range credits {:?}", course, credits)
+            }
+            Issue::UnknownCourseType {
+                course,
+                course_type,
+            } => write!(f, "{} has an unknown course type {:?}", course, course_type),
+            Issue::Unreadable { message } => write!(f, "could not be read: {}", message),
+        }
+    }
+}
+
+/// Checks a student document, along with any audit results for it, for data problems
+pub fn validate_student(
+    student: &Student,
+    results: &[AreaOfStudy],
+    options: &ValidateOptions,
+) -> Vec<Issue> {
+    let mut issues = vec![];
+
+    let mut clbid_counts: BTreeMap<ClassLabId, usize> = BTreeMap::new();
+    for course in &student.courses {
+        *clbid_counts.entry(course.unique_id()).or_default() += 1;
+    }
+    issues.extend(
+        clbid_counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(clbid, count)| Issue::DuplicateClbid { clbid, count }),
+    );
+
+    for course in &student.courses {
+        let name = course.course_with_term();
+
+        if course.parsed_term().is_err() {
+            issues.push(Issue::MalformedTerm {
+                course: name.clone(),
+                year: course.year.clone(),
+                term: course.term.clone(),
+            });
+        }
+
+        let credits_ok = Decimal::from_str(&course.credits)
+            .map(|credits| {
+                !credits.is_sign_negative() && options.max_credits.is_none_or(|max| credits <= max)
+            })
+            .unwrap_or(false);
+        if !credits_ok {
+            issues.push(Issue::CreditsOutOfRange {
+                course: name.clone(),
+                credits: course.credits.clone(),
+            });
+        }
+
+        if !COURSE_TYPES.contains(&course.course_type.as_str()) {
+            issues.push(Issue::UnknownCourseType {
+                course: name,
+                course_type: course.course_type.clone(),
+            });
+        }
+    }
+
+    for result in results {
+        for clbid in result.result.claimed_clbids() {
+            if student.get_class_by_clbid(&clbid).is_none() {
+                issues.push(Issue::UnknownClaim {
+                    area: result.code.clone(),
+                    clbid,
+                });
+            }
+        }
+    }
+
+    issues
+}
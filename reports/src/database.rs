@@ -2,17 +2,36 @@ use crate::ReportType;
 use anyhow;
 use dotenv::dotenv;
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
-use postgres::{config::SslMode, Client, Config};
+pub use postgres::Client;
+use postgres::{config::SslMode, Config};
 use postgres_openssl::MakeTlsConnector;
 
+/// Connects using $DATABASE_URL if it's set, and the PG* variables otherwise
 pub fn connect() -> anyhow::Result<Client> {
+    connect_with_default(None)
+}
+
+/// Like `connect`, but falls back to the given URL (eg, from a config file) before the PG* variables
+pub fn connect_with_default(database_url: Option<&str>) -> anyhow::Result<Client> {
     dotenv().ok();
 
-    let mut config = Config::new();
-    config.user(&std::env::var("PGUSER").expect("PGUSER env var is required"));
-    config.password(&std::env::var("PGPASSWORD").expect("PGPASSWORD env var is required"));
-    config.host(&std::env::var("PGHOST").expect("PGHOST env var is required"));
-    config.dbname(&std::env::var("PGDATABASE").expect("PGDATABASE env var is required"));
+    let url = std::env::var("DATABASE_URL")
+        .ok()
+        .filter(|url| !url.is_empty())
+        .or_else(|| database_url.map(String::from));
+
+    let mut config = match url {
+        Some(url) => config_from_url(&url)?,
+        None => {
+            let mut config = Config::new();
+            config.user(&std::env::var("PGUSER").expect("PGUSER env var is required"));
+            config.password(std::env::var("PGPASSWORD").expect("PGPASSWORD env var is required"));
+            config.host(&std::env::var("PGHOST").expect("PGHOST env var is required"));
+            config.dbname(&std::env::var("PGDATABASE").expect("PGDATABASE env var is required"));
+            config
+        }
+    };
+
     config.application_name("degreepath-reports");
     config.ssl_mode(SslMode::Require);
 
@@ -25,6 +44,16 @@ pub fn connect() -> anyhow::Result<Client> {
     Ok(client)
 }
 
+fn config_from_url(url: &str) -> anyhow::Result<Config> {
+    match url.split_once("://") {
+        Some(("postgres", _)) | Some(("postgresql", _)) => Ok(url.parse::<Config>()?),
+        Some(("sqlite", _)) => anyhow::bail!(
+            "the reports only read results from Postgres; sqlite databases are for the testbed"
+        ),
+        _ => anyhow::bail!("DATABASE_URL must be a postgres:// URL"),
+    }
+}
+
 pub fn record_report(
     client: &mut Client,
     report_type: &crate::ReportType,
@@ -81,3 +110,65 @@ pub fn collect_area_codes(client: &mut Client) -> anyhow::Result<Vec<String>> {
 
     Ok(results)
 }
+
+/// Summarizes the stored results for the given areas, so that callers can tell when any were added
+/// or replaced; any new audit changes the row count or the latest timestamp
+pub fn results_fingerprint(client: &mut Client, area_codes: &[String]) -> anyhow::Result<String> {
+    let row = client.query_one(
+        "
+        SELECT count(*), cast(max(ts) as text)
+        FROM result
+        WHERE area_code = ANY($1)
+    ",
+        &[&area_codes],
+    )?;
+
+    let count: i64 = row.get(0);
+    let latest: Option<String> = row.get(1);
+
+    Ok(format!(
+        "{} results, latest at {}",
+        count,
+        latest.unwrap_or_else(|| String::from("never"))
+    ))
+}
+
+/// Expands any `*` wildcards in the given area codes against the areas with active results
+pub fn expand_area_codes(client: &mut Client, patterns: &[String]) -> anyhow::Result<Vec<String>> {
+    if !patterns.iter().any(|p| p.contains('*')) {
+        return Ok(patterns.to_vec());
+    }
+
+    let known = collect_area_codes(client)?;
+
+    let mut expanded: Vec<String> = vec![];
+    for pattern in patterns {
+        if pattern.contains('*') {
+            for code in known.iter().filter(|code| glob_matches(pattern, code)) {
+                if !expanded.contains(code) {
+                    expanded.push(code.clone());
+                }
+            }
+        } else if !expanded.contains(pattern) {
+            expanded.push(pattern.clone());
+        }
+    }
+
+    Ok(expanded)
+}
+
+pub(crate) fn glob_matches(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let text = match text.strip_prefix(prefix) {
+                Some(text) => text,
+                None => return false,
+            };
+
+            (0..=text.len())
+                .filter(|&i| text.is_char_boundary(i))
+                .any(|i| glob_matches(rest, &text[i..]))
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::students::StudentRecord;
+use askama_escape::{escape, Html};
+use itertools::Itertools;
+
+pub(crate) fn print_as_csv(records: &[StudentRecord]) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record(["student id", "name", "requirement", "shortfall"])?;
+
+    for record in records {
+        for (requirement, gap) in record.result.requirement_gaps() {
+            writer.write_record([
+                record.student.stnum.as_str(),
+                record.student.name_sort.as_str(),
+                requirement.as_str(),
+                gap.to_string().as_str(),
+            ])?;
+        }
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+pub(crate) fn print_as_html<W: std::io::Write>(
+    writer: &mut W,
+    records: &[StudentRecord],
+) -> anyhow::Result<()> {
+    writeln!(writer, r#"<meta charset="utf-8">"#)?;
+    writeln!(writer, r#"<table class="dp-report">"#)?;
+
+    writeln!(writer, "<thead>")?;
+    writeln!(writer, "<tr>")?;
+    for th in ["student id", "name", "unmet requirements"].iter() {
+        writeln!(writer, "<th>{}</th>", th)?;
+    }
+    writeln!(writer, "</tr>")?;
+    writeln!(writer, "</thead>")?;
+
+    writeln!(writer, "<tbody>")?;
+    for record in records {
+        let gaps = record.result.requirement_gaps();
+        if gaps.is_empty() {
+            continue;
+        }
+
+        // requirement_gaps walks the tree in order, so each requirement's gaps are already together
+        let items = gaps
+            .into_iter()
+            .group_by(|(requirement, _)| requirement.clone())
+            .into_iter()
+            .map(|(requirement, gaps)| {
+                let shortfall = gaps.map(|(_, gap)| gap.to_string()).join("; ");
+                format!(
+                    "<li>{}: {}</li>",
+                    escape(&requirement, Html),
+                    escape(&shortfall, Html)
+                )
+            })
+            .join("");
+
+        writeln!(writer, "<tr>")?;
+        writeln!(writer, "<td>{}</td>", escape(&record.student.stnum, Html))?;
+        writeln!(
+            writer,
+            "<td>{}</td>",
+            escape(&record.student.name_sort, Html)
+        )?;
+        writeln!(writer, "<td><ul>{}</ul></td>", items)?;
+        writeln!(writer, "</tr>")?;
+    }
+    writeln!(writer, "</tbody>")?;
+    writeln!(writer, "</table>")?;
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::students::StudentRecord;
+use askama_escape::{escape, Html};
+use std::collections::BTreeMap;
+
+type Groups = BTreeMap<String, Vec<StudentRecord>>;
+
+/// How many students in a group have completed the area, and how many haven't
+fn subtotal(records: &[StudentRecord]) -> (usize, usize) {
+    let passing = records.iter().filter(|r| r.result.ok).count();
+
+    (passing, records.len() - passing)
+}
+
+pub(crate) fn print_as_html<F>(groups: &Groups, render: F) -> anyhow::Result<String>
+where
+    F: Fn(&[StudentRecord]) -> anyhow::Result<String>,
+{
+    let mut output = String::new();
+
+    for (group, records) in groups {
+        let (passing, failing) = subtotal(records);
+
+        output.push_str(&format!(
+            "<section class=\"dp-group\">\n<h1>{}</h1>\n",
+            escape(group, Html)
+        ));
+        output.push_str(&render(records)?);
+        output.push_str(&format!(
+            "<table class=\"dp-subtotal\"><tr><th>subtotal</th><td>{} passing</td><td>{} not passing</td></tr></table>\n</section>\n",
+            passing, failing
+        ));
+    }
+
+    Ok(output)
+}
+
+// every row gets a leading "group" column, under the one header row that each group's rendering
+// starts with; the subtotals follow in a separate table after a blank row
+pub(crate) fn print_as_csv<F>(groups: &Groups, render: F) -> anyhow::Result<String>
+where
+    F: Fn(&[StudentRecord]) -> anyhow::Result<String>,
+{
+    let mut writer = csv::WriterBuilder::new().flexible(true).from_writer(vec![]);
+    let mut header: Option<csv::StringRecord> = None;
+
+    for (group, records) in groups {
+        let rendered = render(records)?;
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(rendered.as_bytes());
+
+        for (i, row) in reader.records().enumerate() {
+            let row = row?;
+
+            if i == 0 {
+                match &header {
+                    Some(header) if *header == row => continue,
+                    Some(_) => {}
+                    None => {
+                        writer.write_record(std::iter::once("group").chain(row.iter()))?;
+                        header = Some(row);
+                        continue;
+                    }
+                }
+            }
+
+            writer.write_record(std::iter::once(group.as_str()).chain(row.iter()))?;
+        }
+    }
+
+    writer.write_record([""])?;
+    writer.write_record(["group", "passing", "not passing"])?;
+    for (group, records) in groups {
+        let (passing, failing) = subtotal(records);
+        writer.write_record([group.clone(), passing.to_string(), failing.to_string()])?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
@@ -0,0 +1,56 @@
+"use strict";
+
+// sorts a table's body by the clicked column; clicking again reverses the order
+function sortBy(table, th) {
+    let index = Array.from(th.parentNode.children).indexOf(th);
+    let direction = th.dataset.sort === "asc" ? "desc" : "asc";
+
+    for (let other of th.parentNode.children) {
+        delete other.dataset.sort;
+    }
+    th.dataset.sort = direction;
+
+    let tbody = table.tBodies[0];
+    let rows = Array.from(tbody.rows);
+    rows.sort((a, b) => {
+        let left = a.cells[index] ? a.cells[index].textContent.trim() : "";
+        let right = b.cells[index] ? b.cells[index].textContent.trim() : "";
+        let order = left.localeCompare(right, undefined, { numeric: true });
+        return direction === "asc" ? order : -order;
+    });
+
+    for (let row of rows) {
+        tbody.appendChild(row);
+    }
+}
+
+// hides any student whose name or number doesn't contain the filter text
+function filterStudents(text) {
+    let needle = text.trim().toLowerCase();
+
+    for (let row of document.querySelectorAll("tr[data-student]")) {
+        row.hidden = needle !== "" && !row.dataset.student.includes(needle);
+    }
+
+    for (let section of document.querySelectorAll("details.dp-section")) {
+        let visible = section.querySelectorAll("tr[data-student]:not([hidden])").length;
+        section.querySelector(".dp-count").textContent = `(${visible})`;
+    }
+}
+
+document.addEventListener("DOMContentLoaded", () => {
+    for (let table of document.querySelectorAll("table.dp-report")) {
+        let header = table.tHead && table.tHead.rows[0];
+        if (!header) {
+            continue;
+        }
+
+        for (let th of header.cells) {
+            th.addEventListener("click", () => sortBy(table, th));
+        }
+    }
+
+    let input = document.querySelector(".dp-filter input");
+    input.addEventListener("input", () => filterStudents(input.value));
+    filterStudents(input.value);
+});
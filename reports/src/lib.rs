@@ -1,9 +1,19 @@
 pub mod database;
+mod gap_report;
+mod grouped;
+pub mod local_audit;
+pub mod logging;
+mod mail_merge;
 mod major_report;
 mod major_summary;
+mod overview;
+pub mod snapshot;
+pub mod stats;
 // mod structs;
 pub mod students;
 
+pub use major_report::{CsvLayout, CsvOptions, StreamFormat};
+use std::collections::BTreeMap;
 use students::StudentRecord;
 
 pub enum ReportType {
@@ -24,4 +34,112 @@ pub fn run_report(records: &[StudentRecord], report_type: &ReportType) -> anyhow
     Ok(String::from(std::str::from_utf8(&inner_buff)?))
 }
 
+/// The full report as a single self-contained page that can be sorted and filtered in a browser
+pub fn run_interactive_report(records: &[StudentRecord]) -> anyhow::Result<String> {
+    let mut buff = std::io::Cursor::new(Vec::new());
+    major_report::print_as_interactive_html(&mut buff, records)?;
+
+    Ok(String::from_utf8(buff.into_inner())?)
+}
+
+pub fn run_report_as_xlsx(records: &[StudentRecord]) -> anyhow::Result<Vec<u8>> {
+    major_report::print_as_xlsx(records)
+}
+
+/// A one-row-per-student table of overall status and progress, without any per-course columns
+pub fn run_overview(records: &[StudentRecord]) -> anyhow::Result<String> {
+    let mut buff = std::io::Cursor::new(Vec::new());
+    overview::print_as_html(&mut buff, records)?;
+
+    Ok(String::from_utf8(buff.into_inner())?)
+}
+
+pub fn run_overview_as_csv(records: &[StudentRecord]) -> anyhow::Result<String> {
+    Ok(String::from_utf8(overview::print_as_csv(records)?)?)
+}
+
+/// Lists each student's unmet requirements, and what each one is still missing
+pub fn run_gap_report(records: &[StudentRecord]) -> anyhow::Result<String> {
+    let mut buff = std::io::Cursor::new(Vec::new());
+    gap_report::print_as_html(&mut buff, records)?;
+
+    Ok(String::from_utf8(buff.into_inner())?)
+}
+
+pub fn run_gap_report_as_csv(records: &[StudentRecord]) -> anyhow::Result<String> {
+    Ok(String::from_utf8(gap_report::print_as_csv(records)?)?)
+}
+
+/// Renders each group's students with `render`, under a heading for the group and followed by a
+/// count of how many have and haven't completed the area
+pub fn run_grouped_report<F>(
+    groups: &BTreeMap<String, Vec<StudentRecord>>,
+    render: F,
+) -> anyhow::Result<String>
+where
+    F: Fn(&[StudentRecord]) -> anyhow::Result<String>,
+{
+    grouped::print_as_html(groups, render)
+}
+
+pub fn run_grouped_report_as_csv<F>(
+    groups: &BTreeMap<String, Vec<StudentRecord>>,
+    render: F,
+) -> anyhow::Result<String>
+where
+    F: Fn(&[StudentRecord]) -> anyhow::Result<String>,
+{
+    grouped::print_as_csv(groups, render)
+}
+
+/// Student numbers mapped to their advisor's name and email address
+pub type Advisors = BTreeMap<String, (String, String)>;
+
+pub fn load_advisors(path: &std::path::Path) -> anyhow::Result<Advisors> {
+    mail_merge::load_advisors(&std::fs::read_to_string(path)?)
+}
+
+/// One row per student with their advisor, status, and a sentence on each unmet requirement
+pub fn run_mail_merge_as_csv(
+    records: &[StudentRecord],
+    advisors: &Advisors,
+) -> anyhow::Result<String> {
+    Ok(String::from_utf8(mail_merge::print_as_csv(
+        records, advisors,
+    )?)?)
+}
+
+pub fn run_reports_as_xlsx(areas: &[(String, Vec<StudentRecord>)]) -> anyhow::Result<Vec<u8>> {
+    major_report::print_areas_as_xlsx(areas)
+}
+
+pub fn run_report_as_csv(
+    records: &[StudentRecord],
+    options: &CsvOptions,
+) -> anyhow::Result<String> {
+    let buff = major_report::print_as_csv(records, options)?;
+    Ok(String::from_utf8(buff)?)
+}
+
+/// Writes an area's report straight from the database, holding only a batch of students at a time
+pub fn stream_report<W: std::io::Write>(
+    client: &mut postgres::Client,
+    area_code: &str,
+    options: &formatter::to_record::RecordOptions,
+    filter: &students::StudentFilter,
+    monitor: &mut students::Monitor,
+    format: &StreamFormat,
+    writer: W,
+) -> anyhow::Result<Vec<students::RowError>> {
+    major_report::stream(client, area_code, options, filter, monitor, format, writer)
+}
+
+/// Bounds how many threads parse results at once; 0 leaves it up to rayon
+pub fn set_jobs(jobs: usize) -> anyhow::Result<()> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build_global()?;
+    Ok(())
+}
+
 // pub fn save_report()
@@ -0,0 +1,69 @@
+use anyhow::{anyhow, bail, Context};
+use formatter::area_of_study::AreaOfStudy;
+use formatter::student::Student;
+use std::path::Path;
+use std::process::Command;
+
+pub fn read_student(path: &Path) -> anyhow::Result<Student> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+
+    serde_json::from_str(&contents)
+        .with_context(|| format!("{} is not a student document", path.display()))
+}
+
+/// Audits a student file against an area file. The audit engine itself lives in the Python
+/// package, so this runs `audit_command` (for example, `python3 -m dp`) and reads back its JSON.
+pub fn audit_files(
+    audit_command: &str,
+    area_file: &Path,
+    student_file: &Path,
+) -> anyhow::Result<AreaOfStudy> {
+    let mut parts = audit_command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow!("the audit command must not be empty"))?;
+
+    tracing::trace!(command = audit_command, area = ?area_file, student = ?student_file, "running the auditor");
+
+    let output = Command::new(program)
+        .args(parts)
+        .arg("--area")
+        .arg(area_file)
+        .arg("--student")
+        .arg(student_file)
+        .args(["--json", "--loglevel", "warn"])
+        .output()
+        .with_context(|| format!("could not run {}", program))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.trim_end() {
+            "" => bail!("{} exited with {}", program, output.status),
+            stderr => bail!("{} exited with {}:\n{}", program, output.status, stderr),
+        }
+    }
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let json = stdout
+        .lines()
+        .rev()
+        .find(|line| line.starts_with('{'))
+        .ok_or_else(|| anyhow!("the auditor did not print a result"))?;
+
+    Ok(serde_json::from_str(json)?)
+}
+
+/// A digest of an area file's contents, so that results audited against one version of an area
+/// aren't mixed up with another's
+pub fn area_fingerprint(area_file: &Path) -> anyhow::Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let contents = std::fs::read(area_file)
+        .with_context(|| format!("could not read {}", area_file.display()))?;
+
+    Ok(Sha256::digest(&contents)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
@@ -0,0 +1,23 @@
+use std::io::IsTerminal;
+use tracing::Level;
+
+/// Sends log messages to stderr. With no `-v`, only progress and problems are logged; each `-v`
+/// adds more detail, down to a span for every student and requirement at `-vv`.
+pub fn init(verbosity: u64, json: bool) {
+    let level = match verbosity {
+        0 => Level::INFO,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal());
+
+    if json {
+        subscriber.json().init();
+    } else {
+        subscriber.init();
+    }
+}
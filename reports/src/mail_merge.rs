@@ -0,0 +1,89 @@
+use crate::students::StudentRecord;
+use crate::Advisors;
+use itertools::Itertools;
+
+const HEADER: [&str; 6] = [
+    "student id",
+    "name",
+    "advisor",
+    "advisor email",
+    "status",
+    "summary",
+];
+
+/// Reads a CSV of advisors, with "student id" and "advisor email" columns and an optional
+/// "advisor" column for the advisor's name
+pub(crate) fn load_advisors(contents: &str) -> anyhow::Result<Advisors> {
+    let mut reader = csv::Reader::from_reader(contents.as_bytes());
+
+    let headers = reader.headers()?.clone();
+    let column = |name: &str| headers.iter().position(|h| h.trim() == name);
+
+    let stnum_col = column("student id")
+        .ok_or_else(|| anyhow::anyhow!("the advisors file needs a \"student id\" column"))?;
+    let email_col = column("advisor email")
+        .ok_or_else(|| anyhow::anyhow!("the advisors file needs an \"advisor email\" column"))?;
+    let name_col = column("advisor");
+
+    let mut advisors = Advisors::new();
+    for row in reader.records() {
+        let row = row?;
+        let field = |i: usize| row.get(i).unwrap_or_default().trim().to_string();
+
+        advisors.insert(
+            field(stnum_col),
+            (name_col.map(field).unwrap_or_default(), field(email_col)),
+        );
+    }
+
+    Ok(advisors)
+}
+
+/// One row per student, for sending each of them (or their advisor) a note about what's left.
+/// Advisors from the sidecar file win over the ones in the student document.
+pub(crate) fn print_as_csv(
+    records: &[StudentRecord],
+    advisors: &Advisors,
+) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record(HEADER)?;
+
+    for record in records {
+        let student = &record.student;
+
+        let (advisor, email) = match advisors.get(&student.stnum) {
+            Some((name, email)) => (name.clone(), email.clone()),
+            None => match &student.advisor {
+                Some(a) => (a.name.clone(), a.email.clone().unwrap_or_default()),
+                None => (String::new(), String::new()),
+            },
+        };
+
+        let status = record
+            .result
+            .to_summary()
+            .status
+            .as_classname()
+            .trim_start_matches("status--")
+            .to_string();
+
+        let summary = record
+            .result
+            .requirement_gaps()
+            .into_iter()
+            .map(|(requirement, gap)| format!("{}: {}", requirement, gap))
+            .join("; ");
+
+        writer.write_record([
+            student.stnum.as_str(),
+            student.name.as_str(),
+            advisor.as_str(),
+            email.as_str(),
+            status.as_str(),
+            summary.as_str(),
+        ])?;
+    }
+
+    Ok(writer.into_inner()?)
+}
@@ -1,54 +1,381 @@
-use crate::students::{StudentRecord, TableGroup, TableKey};
-use formatter::to_record::Record;
+use crate::database::glob_matches;
+use crate::students::{
+    anonymize, map_students, Monitor, RowError, StudentFilter, StudentRecord, TableGroup, TableKey,
+    PARSE_BATCH_SIZE,
+};
+use formatter::to_record::{Record, RecordOptions};
 use itertools::Itertools;
-use std::collections::{BTreeMap, BTreeSet};
+use rust_xlsxwriter::{Color, Format, Workbook};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 #[derive(Default, Debug)]
 struct Table {
     caption: String,
     header: Vec<TableKey>,
     rows: Vec<BTreeMap<TableKey, Vec<Record>>>,
+    /// Each row's student name and number, lowercased, for the interactive report's filter box
+    students: Vec<String>,
 }
 
-pub(crate) fn print_as_html<'a, W: std::io::Write>(
+pub(crate) fn print_as_html<W: std::io::Write>(
     mut writer: &mut W,
     results: &[StudentRecord],
 ) -> anyhow::Result<()> {
+    let tables = build_tables(results);
+
+    render_tables(&mut writer, &tables)?;
+
+    Ok(())
+}
+
+const INTERACTIVE_CSS: &str = include_str!("interactive/report.css");
+const INTERACTIVE_JS: &str = include_str!("interactive/report.js");
+
+/// The HTML report as a standalone page, with sortable columns, a filter box, and a collapsible
+/// section per table; the styles and scripts are inlined so the file can be passed around as-is
+pub(crate) fn print_as_interactive_html<W: std::io::Write>(
+    mut writer: &mut W,
+    results: &[StudentRecord],
+) -> anyhow::Result<()> {
+    let tables = build_tables(results);
+
+    writeln!(&mut writer, "<!doctype html>")?;
+    writeln!(&mut writer, "<html>")?;
+    writeln!(&mut writer, "<head>")?;
+    writeln!(&mut writer, r#"<meta charset="utf-8">"#)?;
+    writeln!(&mut writer, "<style>\n{}</style>", INTERACTIVE_CSS)?;
+    writeln!(&mut writer, "<script>\n{}</script>", INTERACTIVE_JS)?;
+    writeln!(&mut writer, "</head>")?;
+    writeln!(&mut writer, "<body>")?;
+
+    writeln!(&mut writer, r#"<div class="dp-filter">"#)?;
+    writeln!(
+        &mut writer,
+        r#"<input type="search" placeholder="Filter by student name or number" autofocus>"#
+    )?;
+    writeln!(&mut writer, "</div>")?;
+
+    for table in &tables {
+        writeln!(&mut writer, r#"<details class="dp-section" open>"#)?;
+        writeln!(
+            &mut writer,
+            r#"<summary><h2>{}</h2> <span class="dp-count">({})</span></summary>"#,
+            table.caption,
+            table.rows.len()
+        )?;
+        render_table(&mut writer, table)?;
+        writeln!(&mut writer, "</details>")?;
+    }
+
+    writeln!(&mut writer, "</body>")?;
+    writeln!(&mut writer, "</html>")?;
+
+    Ok(())
+}
+
+/// How a report is laid out as CSV
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum CsvLayout {
+    /// One row per student and one column per requirement, as in the HTML report
+    #[default]
+    Wide,
+    /// One row per student per requirement
+    Long,
+}
+
+impl std::str::FromStr for CsvLayout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wide" => Ok(CsvLayout::Wide),
+            "long" => Ok(CsvLayout::Long),
+            _ => Err(format!("expected one of wide, long; got {:?}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CsvOptions {
+    pub layout: CsvLayout,
+    /// Only keep requirement columns whose titles match one of these patterns; empty keeps them all
+    pub columns: Vec<String>,
+    /// Drop requirement columns whose titles match any of these patterns
+    pub exclude_columns: Vec<String>,
+}
+
+impl CsvOptions {
+    // patterns may use `*` wildcards, and ignore case; the columns that say who the student is are
+    // always kept
+    fn keeps_column(&self, title: &str) -> bool {
+        let key = TableKey {
+            title: title.to_string(),
+            subtitle: None,
+        };
+        if key.is_meta() {
+            return true;
+        }
+
+        let title = title.to_lowercase();
+        let matches = |pattern: &String| glob_matches(&pattern.to_lowercase(), &title);
+
+        (self.columns.is_empty() || self.columns.iter().any(matches))
+            && !self.exclude_columns.iter().any(matches)
+    }
+}
+
+pub(crate) fn print_as_csv(
+    results: &[StudentRecord],
+    options: &CsvOptions,
+) -> anyhow::Result<Vec<u8>> {
+    match options.layout {
+        CsvLayout::Wide => render_wide_csv(&build_tables(results), options),
+        CsvLayout::Long => render_long_csv(results, options),
+    }
+}
+
+/// What `stream` writes: the plain HTML report, or the report as CSV
+#[derive(Debug, Clone)]
+pub enum StreamFormat {
+    Html,
+    Csv(CsvOptions),
+}
+
+// where one student's row goes, found without keeping the row itself
+struct Placement {
+    table: (TableGroup, Vec<String>),
+    catalog: String,
+    sort_key: (String, String),
+    stnum: String,
+}
+
+/// Writes the same output as `print_as_html` or `print_as_csv`, but never holds more than one
+/// batch of records at a time. The first pass only works out which table each student belongs in
+/// and in what order; the second re-reads the rows table by table, a batch at a time, and writes
+/// them out. Both passes read from one snapshot, so they see the same results.
+pub(crate) fn stream<W: std::io::Write>(
+    client: &mut postgres::Client,
+    area_code: &str,
+    options: &RecordOptions,
+    filter: &StudentFilter,
+    monitor: &mut Monitor,
+    format: &StreamFormat,
+    mut writer: W,
+) -> anyhow::Result<Vec<RowError>> {
+    let mut tx = client
+        .build_transaction()
+        .isolation_level(postgres::IsolationLevel::RepeatableRead)
+        .read_only(true)
+        .start()?;
+
+    let to_record = |student, result| {
+        let mut record = StudentRecord::new(student, result, options);
+        let stnum = record.student.stnum.clone();
+
+        if let Some(salt) = &filter.anonymize_salt {
+            anonymize(&mut record, salt);
+        }
+
+        (stnum, record)
+    };
+
+    let (placements, errors) =
+        map_students(&mut tx, area_code, filter, monitor, |student, result| {
+            let (stnum, record) = to_record(student, result);
+
+            Placement {
+                table: (record.group, record.emphasis_requirement_names),
+                catalog: record.student.catalog,
+                sort_key: (record.student.name_sort, record.student.stnum),
+                stnum,
+            }
+        })?;
+
+    // the long layout is one flat list, in the same order that `fetch_filtered_records` sorts by
+    let sections = match format {
+        StreamFormat::Csv(CsvOptions {
+            layout: CsvLayout::Long,
+            ..
+        }) => {
+            let mut placements = placements;
+            placements.sort_by(|a, b| (&a.table.0, &a.sort_key).cmp(&(&b.table.0, &b.sort_key)));
+            vec![(String::new(), vec![], placements)]
+        }
+        _ => {
+            let mut tables: BTreeMap<(TableGroup, Vec<String>), Vec<Placement>> = BTreeMap::new();
+            for placement in placements {
+                tables
+                    .entry(placement.table.clone())
+                    .or_default()
+                    .push(placement);
+            }
+
+            let mut sections = tables
+                .into_iter()
+                .map(|((group, emphasis_names), mut rows)| {
+                    rows.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+                    let catalogs = rows.iter().map(|p| p.catalog.clone()).collect();
+                    (caption(&catalogs, &emphasis_names), group.titles, rows)
+                })
+                .collect::<Vec<_>>();
+
+            sections.sort_by(|a, b| a.0.cmp(&b.0));
+            sections
+        }
+    };
+
+    // calls `write` with each section's records, in order, reading a batch at a time
+    let mut each_record = |rows: &[Placement],
+                           write: &mut dyn FnMut(&StudentRecord) -> anyhow::Result<()>|
+     -> anyhow::Result<()> {
+        for chunk in rows.chunks(PARSE_BATCH_SIZE) {
+            let filter = StudentFilter {
+                students: Some(chunk.iter().map(|p| p.stnum.clone()).collect()),
+                ..filter.clone()
+            };
+
+            let mut batch_monitor = Monitor::default();
+            let (records, _) =
+                map_students(&mut tx, area_code, &filter, &mut batch_monitor, to_record)?;
+            monitor.timings.query += batch_monitor.timings.query;
+            monitor.timings.parse += batch_monitor.timings.parse;
+            monitor.timings.format += batch_monitor.timings.format;
+
+            let mut records = records.into_iter().collect::<HashMap<_, _>>();
+            for placement in chunk {
+                if let Some(record) = records.remove(&placement.stnum) {
+                    write(&record)?;
+                }
+            }
+        }
+
+        Ok(())
+    };
+
+    match format {
+        StreamFormat::Html => {
+            writeln!(&mut writer, r#"<meta charset="utf-8">"#)?;
+
+            for (caption, header, rows) in &sections {
+                if !caption.is_empty() {
+                    writeln!(&mut writer, "<h2>{}</h2>", caption)?;
+                }
+                render_table_head(&mut writer, header)?;
+                each_record(rows, &mut |record| {
+                    render_row(
+                        &mut writer,
+                        header,
+                        &to_row(header, record),
+                        &row_label(record),
+                    )
+                })?;
+                render_table_foot(&mut writer)?;
+            }
+        }
+        StreamFormat::Csv(options) if options.layout == CsvLayout::Wide => {
+            let mut csv_writer = csv::WriterBuilder::new()
+                .flexible(true)
+                .from_writer(&mut writer);
+
+            for (i, (caption, header, rows)) in sections.iter().enumerate() {
+                let kept = write_wide_csv_head(&mut csv_writer, i, caption, header, options)?;
+                each_record(rows, &mut |record| {
+                    write_wide_csv_row(&mut csv_writer, &kept, &to_row(header, record))
+                })?;
+            }
+
+            csv_writer.flush()?;
+        }
+        StreamFormat::Csv(options) => {
+            let mut csv_writer = csv::Writer::from_writer(&mut writer);
+
+            csv_writer.write_record(LONG_CSV_HEADER)?;
+            for (_, _, rows) in &sections {
+                each_record(rows, &mut |record| {
+                    write_long_csv_rows(&mut csv_writer, record, options)
+                })?;
+            }
+
+            csv_writer.flush()?;
+        }
+    }
+
+    tx.commit()?;
+    writer.flush()?;
+
+    Ok(errors)
+}
+
+pub(crate) fn print_as_xlsx(results: &[StudentRecord]) -> anyhow::Result<Vec<u8>> {
+    let tables = build_tables(results);
+
+    render_workbook(&tables)
+}
+
+/// Puts several areas' reports into one workbook, with the area code in front of each sheet's caption
+pub(crate) fn print_areas_as_xlsx(
+    areas: &[(String, Vec<StudentRecord>)],
+) -> anyhow::Result<Vec<u8>> {
+    let tables = areas
+        .iter()
+        .flat_map(|(area_code, results)| {
+            build_tables(results).into_iter().map(move |mut table| {
+                table.caption = format!("{}: {}", area_code, table.caption);
+                table
+            })
+        })
+        .collect::<Vec<_>>();
+
+    render_workbook(&tables)
+}
+
+fn build_tables(results: &[StudentRecord]) -> Vec<Table> {
     let grouped = results
         .iter()
-        .map(|record| ((record.group.clone(), record.emphasis_requirement_names.clone()), record))
+        .map(|record| {
+            (
+                (
+                    record.group.clone(),
+                    record.emphasis_requirement_names.clone(),
+                ),
+                record,
+            )
+        })
         .into_group_map();
 
     let mut tables: Vec<Table> = grouped
         .iter()
         .map(|((group_header, emphasis_names), group)| {
-            to_table(&group_header, &emphasis_names, &group)
+            to_table(group_header, emphasis_names, group)
         })
         .collect();
 
     tables.sort_by_cached_key(|t| t.caption.clone());
 
-    render_tables(&mut writer, &tables)?;
-
-    Ok(())
+    tables
 }
 
-fn to_table<'a>(
-    headers: &TableGroup,
-    emphasis_names: &[String],
-    group: &[&'a StudentRecord],
-) -> Table {
-    let mut current_table = Table::default();
-
+fn to_table(headers: &TableGroup, emphasis_names: &[String], group: &[&StudentRecord]) -> Table {
     let catalogs = group
         .iter()
         .map(|res| res.student.catalog.clone())
-        .collect::<BTreeSet<_>>()
-        .iter()
-        .join(", ");
+        .collect::<BTreeSet<_>>();
+
+    Table {
+        caption: caption(&catalogs, emphasis_names),
+        header: headers.titles.clone(),
+        rows: group
+            .iter()
+            .map(|result| to_row(&headers.titles, result))
+            .collect(),
+        students: group.iter().map(|result| row_label(result)).collect(),
+    }
+}
 
-    // write out a blank line, then a line with the new catalog year
-    current_table.caption = if !emphasis_names.is_empty() {
+fn caption(catalogs: &BTreeSet<String>, emphasis_names: &[String]) -> String {
+    let catalogs = catalogs.iter().join(", ");
+
+    if !emphasis_names.is_empty() {
         format!(
             "Catalog: {}; Emphases: {}",
             catalogs,
@@ -56,36 +383,32 @@ fn to_table<'a>(
         )
     } else {
         format!("Catalog: {}", catalogs)
-    };
-
-    current_table.header = headers.titles.clone();
-
-    current_table.rows = {
-        let mut rows = vec![];
+    }
+}
 
-        for result in group {
-            let mut row: BTreeMap<TableKey, Vec<Record>> = BTreeMap::new();
+fn to_row(header: &[TableKey], result: &StudentRecord) -> BTreeMap<TableKey, Vec<Record>> {
+    let mut row: BTreeMap<TableKey, Vec<Record>> = BTreeMap::new();
 
-            for column in headers.titles.clone() {
-                if let Some(record) = result.get_cell_by_key(&column) {
-                    let entry = row.entry(column).or_default();
-                    entry.push(record.clone());
-                } else {
-                    println!("did not find cell for {:?}", &column);
-                    println!("known cells:");
-                    for cell in result.get_all_cells() {
-                        println!("- {} ({})", cell.0, cell.1);
-                    }
-                }
-            }
-
-            rows.push(row);
+    for column in header {
+        if let Some(record) = result.get_cell_by_key(column) {
+            let entry = row.entry(column.clone()).or_default();
+            entry.push(record.clone());
+        } else {
+            tracing::warn!(
+                stnum = %result.student.stnum,
+                column = ?column,
+                known_cells = ?result.get_all_cells(),
+                "did not find a cell for this column"
+            );
         }
+    }
 
-        rows
-    };
+    row
+}
 
-    current_table
+// the text the interactive report's filter box matches against
+fn row_label(result: &StudentRecord) -> String {
+    format!("{} {}", result.student.name_sort, result.student.stnum).to_lowercase()
 }
 
 fn render_tables<W: std::io::Write>(mut writer: &mut W, tables: &[Table]) -> anyhow::Result<()> {
@@ -95,58 +418,278 @@ fn render_tables<W: std::io::Write>(mut writer: &mut W, tables: &[Table]) -> any
         if !table.caption.is_empty() {
             writeln!(&mut writer, "<h2>{}</h2>", table.caption)?;
         }
-        writeln!(&mut writer, r#"<table class="dp-report">"#)?;
-        writeln!(&mut writer, "<thead>")?;
-        writeln!(&mut writer, "<tr>")?;
-        for th in table.header.iter() {
-            writeln!(&mut writer, "<th>{}</th>", th.title)?;
+        render_table(&mut writer, table)?;
+    }
+
+    Ok(())
+}
+
+fn render_table<W: std::io::Write>(mut writer: &mut W, table: &Table) -> anyhow::Result<()> {
+    render_table_head(&mut writer, &table.header)?;
+    for (tr, student) in table.rows.iter().zip(table.students.iter()) {
+        render_row(&mut writer, &table.header, tr, student)?;
+    }
+    render_table_foot(&mut writer)
+}
+
+fn render_table_head<W: std::io::Write>(
+    mut writer: &mut W,
+    header: &[TableKey],
+) -> anyhow::Result<()> {
+    writeln!(&mut writer, r#"<table class="dp-report">"#)?;
+    writeln!(&mut writer, "<thead>")?;
+    writeln!(&mut writer, "<tr>")?;
+    for th in header.iter() {
+        writeln!(&mut writer, "<th>{}</th>", th.title)?;
+    }
+    writeln!(&mut writer, "</tr>")?;
+    writeln!(&mut writer, "<tr>")?;
+    for th in header.iter() {
+        if let Some(text) = &th.subtitle {
+            writeln!(&mut writer, "<th>{}</th>", text)?;
+        } else {
+            writeln!(&mut writer, "<th></th>")?;
         }
-        writeln!(&mut writer, "</tr>")?;
-        writeln!(&mut writer, "<tr>")?;
-        for th in table.header.iter() {
+    }
+    writeln!(&mut writer, "</tr>")?;
+    writeln!(&mut writer, "</thead>")?;
+
+    writeln!(&mut writer, "<tbody>")?;
+
+    Ok(())
+}
+
+fn render_row<W: std::io::Write>(
+    mut writer: &mut W,
+    header: &[TableKey],
+    tr: &BTreeMap<TableKey, Vec<Record>>,
+    student: &str,
+) -> anyhow::Result<()> {
+    writeln!(
+        &mut writer,
+        r#"<tr data-student="{}">"#,
+        askama_escape::escape(student, askama_escape::Html)
+    )?;
+    for th in header.iter() {
+        let cells = tr.get(th).unwrap();
+
+        for cell in cells {
+            let class_list = [
+                if cell.is_ok() {
+                    "passing"
+                } else {
+                    "not-passing"
+                },
+                cell.status_class(),
+            ]
+            .iter()
+            .join(" ");
+
+            let content = cell.content.iter().map(|c| c.render()).join("<br>");
+
+            writeln!(
+                &mut writer,
+                r#"<td class="{}">{}</td>"#,
+                class_list,
+                &content,
+                // askama_escape::escape(&td, askama_escape::Html)
+            )?;
+        }
+    }
+    writeln!(&mut writer, "</tr>")?;
+
+    Ok(())
+}
+
+fn render_table_foot<W: std::io::Write>(mut writer: &mut W) -> anyhow::Result<()> {
+    writeln!(&mut writer, "</tbody>")?;
+    writeln!(&mut writer, "</table>")?;
+
+    Ok(())
+}
+
+fn plain_text(cell: &Record, separator: &str) -> String {
+    cell.content
+        .iter()
+        .map(|c| {
+            c.render()
+                .replace("<br/>", separator)
+                .replace("<br>", separator)
+        })
+        .join(separator)
+}
+
+// each table gets its own caption and header rows, since the columns differ between tables
+fn render_wide_csv(tables: &[Table], options: &CsvOptions) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::WriterBuilder::new().flexible(true).from_writer(vec![]);
+
+    for (i, table) in tables.iter().enumerate() {
+        let header = write_wide_csv_head(&mut writer, i, &table.caption, &table.header, options)?;
+
+        for tr in table.rows.iter() {
+            write_wide_csv_row(&mut writer, &header, tr)?;
+        }
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+/// Writes the caption and header rows of the `index`th table, and returns the columns it kept
+fn write_wide_csv_head<'a, W: std::io::Write>(
+    writer: &mut csv::Writer<W>,
+    index: usize,
+    caption: &str,
+    header: &'a [TableKey],
+    options: &CsvOptions,
+) -> anyhow::Result<Vec<&'a TableKey>> {
+    if index > 0 {
+        writer.write_record([""])?;
+    }
+
+    let header = header
+        .iter()
+        .filter(|th| options.keeps_column(&th.title))
+        .collect::<Vec<_>>();
+
+    writer.write_record([caption])?;
+    writer.write_record(header.iter().map(|th| th.title.as_str()))?;
+    writer.write_record(header.iter().map(|th| th.subtitle.as_deref().unwrap_or("")))?;
+
+    Ok(header)
+}
+
+fn write_wide_csv_row<W: std::io::Write>(
+    writer: &mut csv::Writer<W>,
+    header: &[&TableKey],
+    tr: &BTreeMap<TableKey, Vec<Record>>,
+) -> anyhow::Result<()> {
+    writer.write_record(header.iter().map(|th| {
+        tr.get(th)
+            .map(|cells| cells.iter().map(|cell| plain_text(cell, "; ")).join("; "))
+            .unwrap_or_default()
+    }))?;
+
+    Ok(())
+}
+
+const LONG_CSV_HEADER: [&str; 8] = [
+    "stnum",
+    "name",
+    "catalog",
+    "emphases",
+    "requirement",
+    "column",
+    "status",
+    "content",
+];
+
+fn render_long_csv(results: &[StudentRecord], options: &CsvOptions) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record(LONG_CSV_HEADER)?;
+
+    for result in results {
+        write_long_csv_rows(&mut writer, result, options)?;
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+fn write_long_csv_rows<W: std::io::Write>(
+    writer: &mut csv::Writer<W>,
+    result: &StudentRecord,
+    options: &CsvOptions,
+) -> anyhow::Result<()> {
+    let emphases = result.emphasis_requirement_names.join(" & ");
+
+    for cell in result
+        .cells
+        .iter()
+        .filter(|cell| options.keeps_column(&cell.title))
+    {
+        writer.write_record([
+            result.student.stnum.as_str(),
+            result.student.name.as_str(),
+            result.student.catalog.as_str(),
+            emphases.as_str(),
+            cell.title.as_str(),
+            cell.subtitle.as_deref().unwrap_or(""),
+            cell.status.as_classname().trim_start_matches("status--"),
+            plain_text(cell, "; ").as_str(),
+        ])?;
+    }
+
+    Ok(())
+}
+
+// worksheet names are limited to 31 characters, and can't contain []:*?/\
+fn worksheet_name(index: usize, caption: &str) -> String {
+    let cleaned = caption
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .collect::<String>();
+
+    format!("{} {}", index + 1, cleaned)
+        .chars()
+        .take(31)
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+fn render_workbook(tables: &[Table]) -> anyhow::Result<Vec<u8>> {
+    let mut workbook = Workbook::new();
+
+    let caption_format = Format::new().set_bold();
+    let header_format = Format::new().set_bold().set_text_wrap();
+    let passing_format = Format::new()
+        .set_text_wrap()
+        .set_background_color(Color::RGB(0xC6EFCE));
+    let failing_format = Format::new()
+        .set_text_wrap()
+        .set_background_color(Color::RGB(0xFFC7CE));
+
+    for (i, table) in tables.iter().enumerate() {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(worksheet_name(i, &table.caption))?;
+
+        worksheet.write_string_with_format(0, 0, &table.caption, &caption_format)?;
+
+        for (col, th) in table.header.iter().enumerate() {
+            let col = col as u16;
+            worksheet.write_string_with_format(1, col, &th.title, &header_format)?;
             if let Some(text) = &th.subtitle {
-                writeln!(&mut writer, "<th>{}</th>", text)?;
-            } else {
-                writeln!(&mut writer, "<th></th>")?;
+                worksheet.write_string_with_format(2, col, text, &header_format)?;
             }
         }
-        writeln!(&mut writer, "</tr>")?;
-        writeln!(&mut writer, "</thead>")?;
 
-        writeln!(&mut writer, "<tbody>")?;
-        for tr in table.rows.iter() {
-            writeln!(&mut writer, "<tr>")?;
-            for th in table.header.iter() {
+        // keep the caption and both header rows visible while scrolling
+        worksheet.set_freeze_panes(3, 0)?;
+
+        for (row, tr) in table.rows.iter().enumerate() {
+            let row = (row + 3) as u32;
+
+            for (col, th) in table.header.iter().enumerate() {
+                let col = col as u16;
                 let cells = tr.get(th).unwrap();
 
-                for cell in cells {
-                    let class_list = vec![
-                        if cell.is_ok() {
-                            "passing"
-                        } else {
-                            "not-passing"
-                        },
-                        cell.status_class(),
-                    ]
-                    .iter()
-                    .join(" ");
-
-                    let content = cell.content.iter().map(|c| c.render()).join("<br>");
-
-                    writeln!(
-                        &mut writer,
-                        r#"<td class="{}">{}</td>"#,
-                        class_list,
-                        &content,
-                        // askama_escape::escape(&td, askama_escape::Html)
-                    )?;
+                if cells.is_empty() {
+                    continue;
                 }
+
+                // a spreadsheet cell can only be written once, so every record in it goes in together
+                let format = if cells.iter().all(|cell| cell.is_ok()) {
+                    &passing_format
+                } else {
+                    &failing_format
+                };
+
+                let content = cells.iter().map(|cell| plain_text(cell, "\n")).join("\n");
+
+                worksheet.write_string_with_format(row, col, &content, format)?;
             }
-            writeln!(&mut writer, "</tr>")?;
         }
-        writeln!(&mut writer, "</tbody>")?;
-        writeln!(&mut writer, "</table>")?;
     }
 
-    Ok(())
+    Ok(workbook.save_to_buffer()?)
 }
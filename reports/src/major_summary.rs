@@ -12,6 +12,7 @@ struct Table {
 }
 
 #[derive(Default, Clone, Debug)]
+#[allow(dead_code)]
 struct StudentOk {
     stnum: String,
     name: String,
@@ -33,7 +34,7 @@ impl TableCounter {
         &mut self,
         classification: &StudentClassification,
         item: StudentOk,
-    ) -> () {
+    ) {
         self.all.push(item.clone());
 
         match classification {
@@ -62,7 +63,7 @@ struct TableKeysHolder {
     fy: TableKey,
 }
 
-pub(crate) fn print_as_html<'a, W: std::io::Write>(
+pub(crate) fn print_as_html<W: std::io::Write>(
     mut writer: &mut W,
     results: &[StudentRecord],
 ) -> anyhow::Result<()> {
@@ -111,7 +112,7 @@ pub(crate) fn print_as_html<'a, W: std::io::Write>(
 
             let matching: Vec<&StudentRecord> = results
                 .iter()
-                .filter(|r| catalogs.iter().any(|catalog| *catalog == r.group.catalog))
+                .filter(|r| catalogs.contains(&r.group.catalog))
                 .collect();
 
             // dbg!(&catalogs, &matching.iter().map(|r| (r.student.name_sort.clone(), r.student.catalog.clone())).collect::<Vec<_>>());
@@ -134,26 +135,26 @@ pub(crate) fn print_as_html<'a, W: std::io::Write>(
     Ok(())
 }
 
-fn to_table<'a>(headers: &TableGroup, group: &[&StudentRecord], keys: &TableKeysHolder) -> Table {
-    let mut table: Table = Table::default();
-
-    table.caption = format!("Catalog: {}", headers.catalog);
-
-    table.header = vec![
-        keys.title.clone(),
-        keys.overall.clone(),
-        keys.sr.clone(),
-        keys.jr.clone(),
-        keys.so.clone(),
-        keys.fy.clone(),
-    ];
+fn to_table(headers: &TableGroup, group: &[&StudentRecord], keys: &TableKeysHolder) -> Table {
+    let mut table: Table = Table {
+        caption: format!("Catalog: {}", headers.catalog),
+        header: vec![
+            keys.title.clone(),
+            keys.overall.clone(),
+            keys.sr.clone(),
+            keys.jr.clone(),
+            keys.so.clone(),
+            keys.fy.clone(),
+        ],
+        ..Table::default()
+    };
 
     // count the number of passing items for each key
     let counters = {
         let mut counters: IndexMap<&TableKey, TableCounter> = headers
             .titles
             .iter()
-            .filter(|title| title.is_meta() == false)
+            .filter(|title| !title.is_meta())
             .map(|title| (title, TableCounter::default()))
             .collect();
 
@@ -161,7 +162,7 @@ fn to_table<'a>(headers: &TableGroup, group: &[&StudentRecord], keys: &TableKeys
             let matches = group.iter().filter_map(|result| {
                 result
                     .get_first_cell_with_key_title(key)
-                    .and_then(|record| Some((result, record)))
+                    .map(|record| (result, record))
             });
 
             for (result, record) in matches {
@@ -0,0 +1,104 @@
+use crate::students::StudentRecord;
+use askama_escape::{escape, Html};
+use rust_decimal::Decimal;
+
+/// One line per student: who they are, and how far along they are in the area
+struct OverviewRow {
+    name: String,
+    stnum: String,
+    catalog: String,
+    emphases: String,
+    status: String,
+    percent_complete: Option<Decimal>,
+    unmet_requirements: usize,
+}
+
+const HEADER: [&str; 7] = [
+    "name",
+    "student id",
+    "catalog",
+    "emphases",
+    "status",
+    "percent complete",
+    "unmet requirements",
+];
+
+fn overview_rows(records: &[StudentRecord]) -> Vec<OverviewRow> {
+    records
+        .iter()
+        .map(|record| {
+            let summary = record.result.to_summary();
+
+            OverviewRow {
+                name: record.student.name_sort.clone(),
+                stnum: record.student.stnum.clone(),
+                catalog: record.student.catalog.clone(),
+                emphases: record.emphasis_requirement_names.join(" & "),
+                status: summary
+                    .status
+                    .as_classname()
+                    .trim_start_matches("status--")
+                    .to_string(),
+                percent_complete: summary.percent_complete,
+                unmet_requirements: summary.unsatisfied_requirements.len(),
+            }
+        })
+        .collect()
+}
+
+impl OverviewRow {
+    fn fields(&self) -> [String; 7] {
+        [
+            self.name.clone(),
+            self.stnum.clone(),
+            self.catalog.clone(),
+            self.emphases.clone(),
+            self.status.clone(),
+            self.percent_complete
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+            self.unmet_requirements.to_string(),
+        ]
+    }
+}
+
+pub(crate) fn print_as_csv(records: &[StudentRecord]) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record(HEADER)?;
+
+    for row in overview_rows(records) {
+        writer.write_record(row.fields())?;
+    }
+
+    Ok(writer.into_inner()?)
+}
+
+pub(crate) fn print_as_html<W: std::io::Write>(
+    writer: &mut W,
+    records: &[StudentRecord],
+) -> anyhow::Result<()> {
+    writeln!(writer, r#"<meta charset="utf-8">"#)?;
+    writeln!(writer, r#"<table class="dp-report">"#)?;
+
+    writeln!(writer, "<thead>")?;
+    writeln!(writer, "<tr>")?;
+    for th in HEADER.iter() {
+        writeln!(writer, "<th>{}</th>", th)?;
+    }
+    writeln!(writer, "</tr>")?;
+    writeln!(writer, "</thead>")?;
+
+    writeln!(writer, "<tbody>")?;
+    for row in overview_rows(records) {
+        writeln!(writer, r#"<tr class="status--{}">"#, row.status)?;
+        for td in row.fields().iter() {
+            writeln!(writer, "<td>{}</td>", escape(td, Html))?;
+        }
+        writeln!(writer, "</tr>")?;
+    }
+    writeln!(writer, "</tbody>")?;
+    writeln!(writer, "</table>")?;
+
+    Ok(())
+}
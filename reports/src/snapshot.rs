@@ -0,0 +1,128 @@
+//! Golden-file checks for the formatters. A corpus is a directory of cases; each case is a
+//! directory holding a `student.json` and a `result.json`, next to the expected output of each
+//! formatter in `prose.txt`, `prose-ascii.txt`, `report.csv`, and `report.html`.
+
+use crate::students::StudentRecord;
+use crate::{run_report, run_report_as_csv, CsvOptions, ReportType};
+use anyhow::Context;
+use formatter::area_of_study::AreaOfStudy;
+use formatter::to_prose::{ProseContext, ProseOptions, SymbolTheme};
+use formatter::to_record::RecordOptions;
+use similar::TextDiff;
+use std::path::{Path, PathBuf};
+
+pub enum Outcome {
+    Matched,
+    /// The formatter's output differs from the golden file; holds a unified diff
+    Changed(String),
+    /// There is no golden file for this output yet
+    Missing,
+    /// The golden file was written from the formatter's current output
+    Updated,
+}
+
+pub struct Snapshot {
+    pub case: String,
+    pub file_name: &'static str,
+    pub outcome: Outcome,
+}
+
+pub fn corpus_cases(corpus: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut cases = vec![];
+    for entry in std::fs::read_dir(corpus)? {
+        let path = entry?.path();
+        if path.join("result.json").exists() {
+            cases.push(path);
+        }
+    }
+    cases.sort();
+
+    Ok(cases)
+}
+
+fn render_case(case: &Path) -> anyhow::Result<Vec<(&'static str, String)>> {
+    let read = |name: &str| {
+        let path = case.join(name);
+        std::fs::read_to_string(&path).with_context(|| format!("could not read {}", path.display()))
+    };
+
+    let student = serde_json::from_str(&read("student.json")?)
+        .with_context(|| format!("{}: student.json is not a student", case.display()))?;
+    let result: AreaOfStudy = serde_json::from_str(&read("result.json")?)
+        .with_context(|| format!("{}: result.json is not a result", case.display()))?;
+
+    let prose = ProseContext {
+        result: &result,
+        student: &student,
+        options: &ProseOptions::default(),
+    }
+    .to_string();
+
+    let ascii_prose = ProseContext {
+        result: &result,
+        student: &student,
+        options: &ProseOptions {
+            symbols: SymbolTheme::plain(),
+            ascii: true,
+            ..ProseOptions::default()
+        },
+    }
+    .to_string();
+
+    let records = [StudentRecord::new(
+        student,
+        result,
+        &RecordOptions::default(),
+    )];
+
+    Ok(vec![
+        ("prose.txt", prose),
+        ("prose-ascii.txt", ascii_prose),
+        (
+            "report.csv",
+            run_report_as_csv(&records, &CsvOptions::default())?,
+        ),
+        ("report.html", run_report(&records, &ReportType::Report)?),
+    ])
+}
+
+/// Renders each case in the corpus and compares it to the golden files; with `update`, the golden
+/// files are rewritten instead of compared
+pub fn check_corpus(corpus: &Path, update: bool) -> anyhow::Result<Vec<Snapshot>> {
+    let mut snapshots = vec![];
+
+    for case in corpus_cases(corpus)? {
+        let case_name = case
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        for (file_name, actual) in render_case(&case)? {
+            let golden_path = case.join(file_name);
+
+            let outcome = match std::fs::read_to_string(&golden_path) {
+                Ok(expected) if expected == actual => Outcome::Matched,
+                _ if update => {
+                    std::fs::write(&golden_path, &actual)?;
+                    Outcome::Updated
+                }
+                Ok(expected) => Outcome::Changed(
+                    TextDiff::from_lines(&expected, &actual)
+                        .unified_diff()
+                        .header(&format!("{}/{}", case_name, file_name), "actual")
+                        .to_string(),
+                ),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Outcome::Missing,
+                Err(err) => return Err(err.into()),
+            };
+
+            snapshots.push(Snapshot {
+                case: case_name.clone(),
+                file_name,
+                outcome,
+            });
+        }
+    }
+
+    Ok(snapshots)
+}
@@ -0,0 +1,171 @@
+use crate::students::StudentRecord;
+use formatter::gaps::Gap;
+use formatter::rule::Rule;
+use indexmap::IndexMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+#[derive(Serialize, Debug, Default)]
+pub struct RequirementStats {
+    pub name: String,
+    pub students: usize,
+    pub passing: usize,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct CourseGapStats {
+    pub course: String,
+    pub students: usize,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct AreaStats {
+    pub students: usize,
+    pub passing: usize,
+    pub average_percent_complete: Option<Decimal>,
+    pub requirements: Vec<RequirementStats>,
+    pub unclaimed_courses: Vec<CourseGapStats>,
+    pub emphases: IndexMap<String, usize>,
+}
+
+fn collect_requirements<'a>(rule: &'a Rule, found: &mut Vec<(&'a str, bool)>) {
+    match rule {
+        Rule::Count(r) => {
+            for item in &r.items {
+                collect_requirements(item, found);
+            }
+        }
+        Rule::Requirement(r) => {
+            found.push((&r.name, r.status.is_passing()));
+            if let Some(result) = &r.result {
+                collect_requirements(result, found);
+            }
+        }
+        Rule::Conditional(r) => {
+            if let Some(branch) = r.active_branch() {
+                collect_requirements(branch, found);
+            }
+        }
+        Rule::Course(_) | Rule::Proficiency(_) | Rule::Query(_) => {}
+    }
+}
+
+pub fn collect_stats(records: &[StudentRecord]) -> AreaStats {
+    let mut requirements: IndexMap<String, RequirementStats> = IndexMap::new();
+    let mut unclaimed: IndexMap<String, usize> = IndexMap::new();
+    let mut emphases: IndexMap<String, usize> = IndexMap::new();
+    let mut percents = vec![];
+    let mut passing = 0;
+
+    for record in records {
+        let summary = record.result.to_summary();
+        if summary.ok {
+            passing += 1;
+        }
+        if let Some(percent) = summary.percent_complete {
+            percents.push(percent);
+        }
+
+        let mut found = vec![];
+        collect_requirements(&record.result.result, &mut found);
+
+        // a requirement name can appear more than once in a tree, but each student counts once,
+        // and only passes it if every occurrence passed
+        let mut by_name: IndexMap<&str, bool> = IndexMap::new();
+        for (name, is_passing) in found {
+            *by_name.entry(name).or_insert(true) &= is_passing;
+        }
+
+        for (name, is_passing) in by_name {
+            let entry = requirements
+                .entry(name.to_string())
+                .or_insert_with(|| RequirementStats {
+                    name: name.to_string(),
+                    ..RequirementStats::default()
+                });
+            entry.students += 1;
+            if is_passing {
+                entry.passing += 1;
+            }
+        }
+
+        let mut missing_courses = BTreeSet::new();
+        for gap in record.result.gaps() {
+            if let Gap::Course { course, .. } = gap {
+                missing_courses.insert(course);
+            }
+        }
+        for course in missing_courses {
+            *unclaimed.entry(course).or_default() += 1;
+        }
+
+        for emphasis in record.result.emphasis_requirements() {
+            if let Some(name) = emphasis.emphasis_name() {
+                *emphases.entry(name.to_string()).or_default() += 1;
+            }
+        }
+    }
+
+    let average_percent_complete = if percents.is_empty() {
+        None
+    } else {
+        let total: Decimal = percents.iter().sum();
+        Some((total / Decimal::from(percents.len())).round_dp(1))
+    };
+
+    let mut unclaimed_courses = unclaimed
+        .into_iter()
+        .map(|(course, students)| CourseGapStats { course, students })
+        .collect::<Vec<_>>();
+    unclaimed_courses.sort_by(|a, b| b.students.cmp(&a.students).then(a.course.cmp(&b.course)));
+
+    emphases.sort_keys();
+
+    AreaStats {
+        students: records.len(),
+        passing,
+        average_percent_complete,
+        requirements: requirements.into_iter().map(|(_, v)| v).collect(),
+        unclaimed_courses,
+        emphases,
+    }
+}
+
+pub fn stats_as_csv(stats: &AreaStats) -> anyhow::Result<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record(["kind", "name", "count", "of"])?;
+
+    let students = stats.students.to_string();
+
+    writer.write_record(["area", "passing", &stats.passing.to_string(), &students])?;
+
+    if let Some(percent) = &stats.average_percent_complete {
+        writer.write_record(["area", "average percent complete", &percent.to_string(), ""])?;
+    }
+
+    for r in &stats.requirements {
+        writer.write_record([
+            "requirement",
+            &r.name,
+            &r.passing.to_string(),
+            &r.students.to_string(),
+        ])?;
+    }
+
+    for c in &stats.unclaimed_courses {
+        writer.write_record([
+            "unclaimed course",
+            &c.course,
+            &c.students.to_string(),
+            &students,
+        ])?;
+    }
+
+    for (name, count) in &stats.emphases {
+        writer.write_record(["emphasis", name, &count.to_string(), &students])?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
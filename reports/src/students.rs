@@ -1,64 +1,377 @@
 use formatter::student::Student;
-use formatter::to_record::{RecordOptions, ToRecord};
+use formatter::to_record::{Cell, RecordOptions, ToRecord};
 use formatter::{area_of_study::AreaOfStudy, to_record::Record};
+use indicatif::{ProgressBar, ProgressStyle};
 use itertools::Itertools;
+use postgres::fallible_iterator::FallibleIterator;
+use rayon::prelude::*;
 use serde_path_to_error;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+// how many rows to read from the database before handing them to the thread pool
+pub(crate) const PARSE_BATCH_SIZE: usize = 256;
+
+/// A row that could not be turned into a record, and so was left out of the report
+#[derive(Debug, Clone)]
+pub struct RowError {
+    pub stnum: String,
+    pub document: &'static str,
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for RowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: could not parse {} at {}: {}",
+            self.stnum, self.document, self.path, self.message
+        )
+    }
+}
+
+impl std::error::Error for RowError {}
+
+/// Narrows which students' results are fetched; every condition is applied in the query
+#[derive(Debug, Clone, Default)]
+pub struct StudentFilter {
+    pub catalog: Option<String>,
+    pub class_year: Option<String>,
+    pub students: Option<Vec<String>>,
+    /// Replace student numbers and names with pseudonyms derived from this salt
+    pub anonymize_salt: Option<String>,
+}
+
+/// A stable stand-in for a student number; the same salt and student always give the same pseudonym
+pub fn pseudonym(salt: &str, stnum: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    let digest = Sha256::new()
+        .chain_update(salt.as_bytes())
+        .chain_update(b":")
+        .chain_update(stnum.as_bytes())
+        .finalize();
+
+    let hex = digest
+        .iter()
+        .take(5)
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+
+    format!("anon-{}", hex)
+}
+
+pub(crate) fn anonymize(record: &mut StudentRecord, salt: &str) {
+    let pseudonym = pseudonym(salt, &record.student.stnum);
+
+    record.student.stnum = pseudonym.clone();
+    record.student.name = pseudonym.clone();
+    record.student.name_sort = pseudonym.clone();
+
+    for cell in record.cells.iter_mut() {
+        if cell.title == "student id" || cell.title == "name" {
+            cell.content = vec![Cell::Text(pseudonym.clone())];
+        }
+    }
+}
+
+/// Where the time went while fetching an area's records. Parsing and formatting happen on several
+/// threads at once, so those are totals across threads rather than wall-clock time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub rows: usize,
+    pub query: Duration,
+    pub parse: Duration,
+    pub format: Duration,
+}
+
+impl std::fmt::Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} rows; query {:?}, parse {:?}, format {:?}",
+            self.rows, self.query, self.parse, self.format
+        )
+    }
+}
+
+/// Reports progress while records are fetched, and collects timings
+#[derive(Debug, Default)]
+pub struct Monitor {
+    pub progress: Option<ProgressBar>,
+    pub timings: Timings,
+}
+
+impl Monitor {
+    /// A monitor which draws a progress bar on stderr, when stderr is a terminal
+    pub fn with_progress(label: &str) -> Monitor {
+        let bar = ProgressBar::new(0);
+        bar.set_style(
+            ProgressStyle::with_template("{prefix} [{bar:30}] {pos}/{len} ({eta})")
+                .expect("the progress template is valid")
+                .progress_chars("=> "),
+        );
+        bar.set_prefix(label.to_string());
+
+        Monitor {
+            progress: Some(bar),
+            timings: Timings::default(),
+        }
+    }
+}
+
+fn result_conditions<'a>(
+    area_code: &'a &'a str,
+    filter: &'a StudentFilter,
+) -> (String, Vec<&'a dyn postgres::types::ToSql>) {
+    let mut conditions = vec![
+        String::from("area_code = $1"),
+        String::from("is_active = true"),
+        String::from("result_version = 3"),
+    ];
+    let mut params: Vec<&dyn postgres::types::ToSql> = vec![area_code];
+
+    if let Some(catalog) = &filter.catalog {
+        params.push(catalog);
+        conditions.push(format!("catalog = ${}", params.len()));
+    }
+    if let Some(class_year) = &filter.class_year {
+        params.push(class_year);
+        conditions.push(format!("student_class = ${}", params.len()));
+    }
+    if let Some(students) = &filter.students {
+        params.push(students);
+        conditions.push(format!("student_id = ANY(${})", params.len()));
+    }
+
+    (conditions.join(" AND "), params)
+}
 
-pub(crate) fn fetch_students(
+/// Streams each active result for the area, parsing and mapping batches of rows on the rayon pool
+/// as they arrive, so that only one batch's JSON text is held in memory at a time.
+/// The output keeps the query's order; rows that fail to parse are returned separately.
+pub(crate) fn map_students<T, F>(
     tx: &mut postgres::Transaction,
     area_code: &str,
-) -> anyhow::Result<Vec<(Student, AreaOfStudy)>> {
-    let stmt = "
-        SELECT cast(result as text) as result
+    filter: &StudentFilter,
+    monitor: &mut Monitor,
+    map: F,
+) -> anyhow::Result<(Vec<T>, Vec<RowError>)>
+where
+    T: Send,
+    F: Fn(Student, AreaOfStudy) -> T + Sync,
+{
+    let area_span = tracing::info_span!("area", code = %area_code);
+    let _entered = area_span.enter();
+
+    let (conditions, params) = result_conditions(&area_code, filter);
+
+    if let Some(bar) = &monitor.progress {
+        let stmt = format!("SELECT count(*) FROM result WHERE {}", conditions);
+        let count: i64 = match tx
+            .query_raw(stmt.as_str(), params.iter().copied())?
+            .next()?
+        {
+            Some(row) => row.get(0),
+            None => 0,
+        };
+        bar.set_length(count as u64);
+    }
+
+    let stmt = format!(
+        "
+        SELECT student_id
+             , cast(result as text) as result
              , cast(input_data as text) as input_data
         FROM result
-        WHERE area_code = $1 AND is_active = true AND result_version = 3
+        WHERE {}
         ORDER BY area_code, student_id
-    ";
+    ",
+        conditions
+    );
 
-    let iter = tx
-        .query(stmt, &[&area_code])?
-        .into_iter()
-        .map(|row| {
-            let result: String = row.get(0);
-            let student: String = row.get(1);
-            (result, student)
-        })
-        .map(|(result, student)| parse_record(&result, &student));
+    let start = Instant::now();
+    let mut rows = tx.query_raw(stmt.as_str(), params)?;
+    monitor.timings.query += start.elapsed();
 
-    Ok(iter.collect())
-}
+    let mut mapped = vec![];
+    let mut errors = vec![];
+    let mut batch: Vec<(String, String, String)> = Vec::with_capacity(PARSE_BATCH_SIZE);
 
-fn parse_record(result: &str, student: &str) -> (Student, AreaOfStudy) {
-    let student_deserializer = &mut serde_json::Deserializer::from_str(student);
-    let student: Student = match serde_path_to_error::deserialize(student_deserializer) {
-        Ok(r) => r,
-        Err(err) => {
-            eprintln!("student error");
+    loop {
+        let start = Instant::now();
+        let row = rows.next()?;
+        monitor.timings.query += start.elapsed();
 
-            eprintln!("{}", student);
-            dbg!(err);
+        if let Some(row) = &row {
+            batch.push((row.get(0), row.get(1), row.get(2)));
+        }
+
+        if batch.len() == PARSE_BATCH_SIZE || (row.is_none() && !batch.is_empty()) {
+            let parsed: Vec<(Result<T, RowError>, Duration, Duration)> = batch
+                .par_drain(..)
+                .map(|(stnum, result, student)| {
+                    // rayon's threads don't inherit the area span, so it's passed along explicitly
+                    let _span = tracing::debug_span!(parent: &area_span, "student", stnum = %stnum)
+                        .entered();
+
+                    let start = Instant::now();
+                    let (student, result) = match parse_record(&stnum, &result, &student) {
+                        Ok(parsed) => parsed,
+                        Err(err) => return (Err(err), start.elapsed(), Duration::default()),
+                    };
+                    let parse_time = start.elapsed();
+
+                    let start = Instant::now();
+                    let item = map(student, result);
+                    (Ok(item), parse_time, start.elapsed())
+                })
+                .collect();
+
+            if let Some(bar) = &monitor.progress {
+                bar.inc(parsed.len() as u64);
+            }
+
+            for (item, parse_time, format_time) in parsed {
+                monitor.timings.rows += 1;
+                monitor.timings.parse += parse_time;
+                monitor.timings.format += format_time;
 
-            panic!();
+                match item {
+                    Ok(item) => mapped.push(item),
+                    Err(err) => errors.push(err),
+                }
+            }
         }
+
+        if row.is_none() {
+            break;
+        }
+    }
+
+    if let Some(bar) = &monitor.progress {
+        bar.finish_and_clear();
+    }
+
+    tracing::debug!(
+        students = mapped.len(),
+        skipped = errors.len(),
+        "fetched results"
+    );
+
+    Ok((mapped, errors))
+}
+
+pub fn fetch_student(
+    client: &mut postgres::Client,
+    student_id: &str,
+    area_code: &str,
+) -> anyhow::Result<Option<(Student, AreaOfStudy)>> {
+    let filter = StudentFilter {
+        students: Some(vec![student_id.to_string()]),
+        ..StudentFilter::default()
     };
 
-    let result_deserializer = &mut serde_json::Deserializer::from_str(result);
-    let result: AreaOfStudy = match serde_path_to_error::deserialize(result_deserializer) {
-        Ok(r) => r,
-        Err(err) => {
-            eprintln!("result error");
+    let mut tx = client.transaction()?;
+    let (mut found, mut errors) = map_students(
+        &mut tx,
+        area_code,
+        &filter,
+        &mut Monitor::default(),
+        |student, result| (student, result),
+    )?;
+    tx.commit()?;
 
-            eprintln!("{}", result);
-            eprintln!("{}", err);
-            eprintln!("at {}", err.path());
-            dbg!(student.stnum);
+    if let Some(err) = errors.pop() {
+        return Err(err.into());
+    }
 
-            panic!();
+    Ok(found.pop())
+}
+
+/// Calls `visit` once per student with an active result, along with all of their active results.
+/// Rows are streamed, so only one student's documents are held at a time.
+pub fn visit_students<F>(
+    client: &mut postgres::Client,
+    mut visit: F,
+) -> anyhow::Result<Vec<RowError>>
+where
+    F: FnMut(Student, Vec<AreaOfStudy>),
+{
+    let stmt = "
+        SELECT student_id
+             , cast(result as text) as result
+             , cast(input_data as text) as input_data
+        FROM result
+        WHERE is_active = true AND result_version = 3
+        ORDER BY student_id, area_code
+    ";
+
+    let mut tx = client.transaction()?;
+    let mut rows = tx.query_raw(stmt, std::iter::empty::<&str>())?;
+
+    let mut errors = vec![];
+    let mut current: Option<(String, Student, Vec<AreaOfStudy>)> = None;
+
+    while let Some(row) = rows.next()? {
+        let stnum: String = row.get(0);
+        let result: String = row.get(1);
+        let student: String = row.get(2);
+
+        let (student, result) = match parse_record(&stnum, &result, &student) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                errors.push(err);
+                continue;
+            }
+        };
+
+        match &mut current {
+            Some((current_stnum, _, results)) if *current_stnum == stnum => results.push(result),
+            _ => {
+                if let Some((_, student, results)) = current.take() {
+                    visit(student, results);
+                }
+                current = Some((stnum, student, vec![result]));
+            }
         }
-    };
+    }
+
+    if let Some((_, student, results)) = current {
+        visit(student, results);
+    }
+
+    drop(rows);
+    tx.commit()?;
+
+    Ok(errors)
+}
 
-    (student, result)
+fn parse_record(
+    stnum: &str,
+    result: &str,
+    student: &str,
+) -> Result<(Student, AreaOfStudy), RowError> {
+    let student_deserializer = &mut serde_json::Deserializer::from_str(student);
+    let student: Student =
+        serde_path_to_error::deserialize(student_deserializer).map_err(|err| RowError {
+            stnum: stnum.to_string(),
+            document: "student",
+            path: err.path().to_string(),
+            message: err.inner().to_string(),
+        })?;
+
+    let result_deserializer = &mut serde_json::Deserializer::from_str(result);
+    let result: AreaOfStudy =
+        serde_path_to_error::deserialize(result_deserializer).map_err(|err| RowError {
+            stnum: stnum.to_string(),
+            document: "result",
+            path: err.path().to_string(),
+            message: err.inner().to_string(),
+        })?;
+
+    Ok((student, result))
 }
 
 #[derive(Debug)]
@@ -72,6 +385,38 @@ pub struct StudentRecord {
 }
 
 impl StudentRecord {
+    pub fn new(student: Student, result: AreaOfStudy, options: &RecordOptions) -> StudentRecord {
+        let cells = result.get_row(&student, options, false);
+        let requirement_names = result.get_requirements();
+        let emphasis_requirement_names = result.emphasis_requirement_names();
+
+        let group = {
+            let titles = cells
+                .iter()
+                // ignore any emphasis columns
+                .filter(|record| !record.is_emphasis())
+                .map(|record| TableKey {
+                    title: record.title.clone(),
+                    subtitle: record.subtitle.clone(),
+                })
+                .collect::<Vec<_>>();
+
+            TableGroup {
+                catalog: student.catalog.clone(),
+                titles,
+            }
+        };
+
+        StudentRecord {
+            student,
+            result,
+            cells,
+            requirement_names,
+            emphasis_requirement_names,
+            group,
+        }
+    }
+
     pub fn get_cell_by_key(&self, key: &TableKey) -> Option<&Record> {
         self.cells
             .iter()
@@ -95,11 +440,6 @@ impl StudentRecord {
     }
 }
 
-#[derive(Debug, PartialEq, Hash, Eq, PartialOrd, Ord)]
-struct SingleTableGroup {
-    pub key: TableKey,
-}
-
 #[derive(Debug, PartialEq, Hash, Eq, PartialOrd, Ord, Clone)]
 pub struct TableGroup {
     pub catalog: String,
@@ -147,13 +487,86 @@ impl TableKey {
     }
 }
 
-pub fn fetch_records<'a>(
-    client: &'a mut postgres::Client,
+/// How to divide a report's students into separate outputs
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SplitBy {
+    Emphasis,
+    Catalog,
+    Advisor,
+}
+
+impl std::str::FromStr for SplitBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "emphasis" => Ok(SplitBy::Emphasis),
+            "catalog" => Ok(SplitBy::Catalog),
+            "advisor" => Ok(SplitBy::Advisor),
+            _ => Err(format!(
+                "expected one of emphasis, catalog, advisor; got {:?}",
+                s
+            )),
+        }
+    }
+}
+
+/// Groups records by emphasis, catalog, or advisor, keeping their order within each group.
+/// Students without an emphasis or advisor are grouped under "none".
+pub fn split_records(
+    records: Vec<StudentRecord>,
+    by: SplitBy,
+) -> BTreeMap<String, Vec<StudentRecord>> {
+    let mut groups: BTreeMap<String, Vec<StudentRecord>> = BTreeMap::new();
+
+    for record in records {
+        let key = match by {
+            SplitBy::Catalog => record.student.catalog.clone(),
+            SplitBy::Emphasis if record.emphasis_requirement_names.is_empty() => {
+                String::from("none")
+            }
+            SplitBy::Emphasis => record.emphasis_requirement_names.join(" & "),
+            SplitBy::Advisor => match &record.student.advisor {
+                Some(advisor) if !advisor.name.is_empty() => advisor.name.clone(),
+                _ => String::from("none"),
+            },
+        };
+
+        groups.entry(key).or_default().push(record);
+    }
+
+    groups
+}
+
+pub fn fetch_records(
+    client: &mut postgres::Client,
     area_code: &str,
-) -> anyhow::Result<Vec<StudentRecord>> {
-    let mut tx = client.transaction()?;
+) -> anyhow::Result<(Vec<StudentRecord>, Vec<RowError>)> {
+    fetch_records_with_options(client, area_code, &RecordOptions::default())
+}
+
+pub fn fetch_records_with_options(
+    client: &mut postgres::Client,
+    area_code: &str,
+    options: &RecordOptions,
+) -> anyhow::Result<(Vec<StudentRecord>, Vec<RowError>)> {
+    fetch_filtered_records(
+        client,
+        area_code,
+        options,
+        &StudentFilter::default(),
+        &mut Monitor::default(),
+    )
+}
 
-    let options = RecordOptions {};
+pub fn fetch_filtered_records(
+    client: &mut postgres::Client,
+    area_code: &str,
+    options: &RecordOptions,
+    filter: &StudentFilter,
+    monitor: &mut Monitor,
+) -> anyhow::Result<(Vec<StudentRecord>, Vec<RowError>)> {
+    let mut tx = client.transaction()?;
 
     // we need to know what columns each student has, so that we can generate a large-enough table.
     // 1. take the (title, subtitle) tuple from each student.
@@ -173,43 +586,21 @@ pub fn fetch_records<'a>(
         }
     */
 
-    let students = fetch_students(&mut tx, &area_code)?;
-
-    tx.commit()?;
+    // the records are still collected, since tables are grouped by each student's set of columns,
+    // but the raw JSON for each row is dropped as soon as it has been turned into a record
+    let (mut records, errors) =
+        map_students(&mut tx, area_code, filter, monitor, |student, result| {
+            let mut record = StudentRecord::new(student, result, options);
 
-    let records = students.into_iter().map(|(student, result)| {
-        let cells = result.get_row(&student, &options, false);
-        let requirement_names = result.get_requirements();
-        let emphasis_requirement_names = result.emphasis_requirement_names();
-
-        let group = {
-            let titles = cells
-                .iter()
-                // ignore any emphasis columns
-                .filter(|record| !record.title.starts_with("Emphasis:"))
-                .map(|record| TableKey {
-                    title: record.title.clone(),
-                    subtitle: record.subtitle.clone(),
-                })
-                .collect::<Vec<_>>();
-
-            TableGroup {
-                catalog: student.catalog.clone(),
-                titles,
+            if let Some(salt) = &filter.anonymize_salt {
+                anonymize(&mut record, salt);
             }
-        };
 
-        StudentRecord {
-            student,
-            result,
-            cells,
-            requirement_names,
-            emphasis_requirement_names,
-            group,
-        }
-    });
+            record
+        })?;
+
+    tx.commit()?;
 
-    let mut records = records.collect::<Vec<_>>();
     records.sort_by_cached_key(|s| {
         (
             s.group.clone(),
@@ -219,5 +610,5 @@ pub fn fetch_records<'a>(
     });
     let records = records;
 
-    Ok(records)
+    Ok((records, errors))
 }
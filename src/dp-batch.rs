@@ -0,0 +1,233 @@
+use anyhow::bail;
+use clap::Clap;
+use rayon::prelude::*;
+use reports::local_audit::{area_fingerprint, audit_files, read_student};
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Audits a directory of student files against one area specification
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// The area specification, as YAML
+    area_file: PathBuf,
+    /// A directory of student documents, as .json files
+    student_dir: PathBuf,
+    /// Where to write each student's result, the combined results.csv, and the done list
+    #[clap(long, default_value = "./batch")]
+    output_dir: PathBuf,
+    /// How many students to audit at once [default: one per CPU]
+    #[clap(long)]
+    jobs: Option<usize>,
+    /// The command that runs the auditor; the area and student arguments are appended
+    #[clap(long, default_value = "python3 -m dp")]
+    audit_command: String,
+    /// Logs more detail; -v for each student's audit, -vv for everything
+    #[clap(short, long, parse(from_occurrences))]
+    verbose: u64,
+    /// Writes log messages to stderr as JSON lines
+    #[clap(long)]
+    log_json: bool,
+    /// Audits every student again, instead of skipping the ones in the done list; needed to reuse an
+    /// output directory for a different area file
+    #[clap(long)]
+    restart: bool,
+}
+
+const DONE_LIST: &str = "done.txt";
+// the fingerprint of the area file that the output directory's results were audited against
+const AREA_STAMP: &str = "area.txt";
+
+fn student_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension() == Some("json".as_ref()) {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+// students are named by their file's stem, in the done list and in the output directory
+fn student_key(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn audit_one(opts: &Opts, student_file: &Path) -> anyhow::Result<()> {
+    let result = audit_files(&opts.audit_command, &opts.area_file, student_file)?;
+
+    let path = opts
+        .output_dir
+        .join(format!("{}.json", student_key(student_file)));
+    std::fs::write(path, serde_json::to_string_pretty(&result)?)?;
+
+    Ok(())
+}
+
+// rebuilt from every result on disk, so that a resumed run still covers the earlier students
+fn write_combined_csv(opts: &Opts, student_files: &[PathBuf]) -> anyhow::Result<()> {
+    let mut writer = csv::Writer::from_path(opts.output_dir.join("results.csv"))?;
+
+    writer.write_record([
+        "student id",
+        "name",
+        "status",
+        "ok",
+        "rank",
+        "max rank",
+        "percent complete",
+        "unmet requirements",
+    ])?;
+
+    for student_file in student_files {
+        let path = opts
+            .output_dir
+            .join(format!("{}.json", student_key(student_file)));
+        if !path.exists() {
+            continue;
+        }
+
+        let student = read_student(student_file)?;
+        let result: formatter::area_of_study::AreaOfStudy =
+            serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        let summary = result.to_summary();
+
+        writer.write_record([
+            student.stnum.as_str(),
+            student.name.as_str(),
+            summary.status.as_classname().trim_start_matches("status--"),
+            if summary.ok { "true" } else { "false" },
+            result.rank.as_str(),
+            result.max_rank.as_str(),
+            &summary
+                .percent_complete
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+            &summary.unsatisfied_requirements.join("; "),
+        ])?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    reports::logging::init(opts.verbose, opts.log_json);
+
+    if let Some(jobs) = opts.jobs {
+        reports::set_jobs(jobs)?;
+    }
+
+    std::fs::create_dir_all(&opts.output_dir)?;
+
+    let done_path = opts.output_dir.join(DONE_LIST);
+    if opts.restart && done_path.exists() {
+        std::fs::remove_file(&done_path)?;
+    }
+
+    let student_files = student_files(&opts.student_dir)?;
+
+    // the done list and results only mean anything for the area they were audited against
+    let stamp_path = opts.output_dir.join(AREA_STAMP);
+    let fingerprint = area_fingerprint(&opts.area_file)?;
+    match std::fs::read_to_string(&stamp_path) {
+        Ok(stamp) if stamp.trim() != fingerprint && !opts.restart => bail!(
+            "{} holds results for a different area file; use another --output-dir, or --restart",
+            opts.output_dir.display()
+        ),
+        Ok(stamp) if stamp.trim() != fingerprint => {
+            // a restart for a new area drops the old area's results, so none are left in results.csv
+            for student_file in &student_files {
+                let path = opts
+                    .output_dir
+                    .join(format!("{}.json", student_key(student_file)));
+                if path.exists() {
+                    std::fs::remove_file(path)?;
+                }
+            }
+        }
+        Ok(_) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            if done_path.exists() {
+                bail!(
+                    "{} has a done list but no record of its area file; use another --output-dir, or --restart",
+                    opts.output_dir.display()
+                );
+            }
+        }
+        Err(err) => return Err(err.into()),
+    }
+    std::fs::write(&stamp_path, format!("{}\n", fingerprint))?;
+
+    let done: BTreeSet<String> = match std::fs::read_to_string(&done_path) {
+        Ok(contents) => contents.lines().map(String::from).collect(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeSet::new(),
+        Err(err) => return Err(err.into()),
+    };
+
+    let pending = student_files
+        .iter()
+        .filter(|path| !done.contains(&student_key(path)))
+        .collect::<Vec<_>>();
+
+    tracing::info!(
+        students = pending.len(),
+        already_done = student_files.len() - pending.len(),
+        "auditing"
+    );
+
+    // each student is added to the done list as soon as their result is written, so an
+    // interrupted run can pick up where it left off
+    let done_list = Mutex::new(
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&done_path)?,
+    );
+
+    let failures = pending
+        .par_iter()
+        .filter_map(|student_file| {
+            let key = student_key(student_file);
+            let _span = tracing::info_span!("student", file = %key).entered();
+            tracing::debug!("auditing");
+
+            let outcome = audit_one(&opts, student_file).and_then(|_| {
+                let mut done_list = done_list.lock().unwrap();
+                writeln!(done_list, "{}", key)?;
+                Ok(())
+            });
+
+            outcome.err().map(|err| (key, err))
+        })
+        .collect::<Vec<_>>();
+
+    write_combined_csv(&opts, &student_files)?;
+
+    for (key, err) in &failures {
+        tracing::error!(file = %key, err = %format_args!("{:#}", err), "could not audit");
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "{} of {} students could not be audited; run again to retry them",
+            failures.len(),
+            pending.len()
+        );
+    }
+
+    println!("{}", opts.output_dir.display());
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use clap::Clap;
+use formatter::to_prose::{ColorMode, ProseContext, ProseOptions};
+use reports::local_audit::{audit_files, read_student};
+use std::path::PathBuf;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Audits a local student file against a local area specification, without a database
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// The area specification, as YAML
+    area_file: PathBuf,
+    /// The student document, as JSON
+    student_file: PathBuf,
+    /// Either prose or json
+    #[clap(long, default_value = "prose")]
+    format: Format,
+    /// Show each rule's path
+    #[clap(long)]
+    show_paths: bool,
+    /// Show each rule's rank
+    #[clap(long)]
+    show_ranks: bool,
+    /// One of auto, always, or never
+    #[clap(long, default_value = "auto")]
+    color: ColorMode,
+    /// The command that runs the auditor; the area and student arguments are appended
+    #[clap(long, default_value = "python3 -m dp")]
+    audit_command: String,
+    /// Logs more detail; -v for each student's audit, -vv for everything
+    #[clap(short, long, parse(from_occurrences))]
+    verbose: u64,
+    /// Writes log messages to stderr as JSON lines
+    #[clap(long)]
+    log_json: bool,
+}
+
+enum Format {
+    Prose,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prose" => Ok(Format::Prose),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("expected one of prose, json; got {:?}", s)),
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    reports::logging::init(opts.verbose, opts.log_json);
+
+    let student = read_student(&opts.student_file)?;
+    let result = audit_files(&opts.audit_command, &opts.area_file, &opts.student_file)?;
+
+    match opts.format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&result)?),
+        Format::Prose => {
+            let options = ProseOptions {
+                show_paths: opts.show_paths,
+                show_ranks: opts.show_ranks,
+                color: opts.color,
+                ..ProseOptions::default()
+            };
+
+            print!(
+                "{}",
+                ProseContext {
+                    result: &result,
+                    student: &student,
+                    options: &options,
+                }
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use clap::Clap;
+use formatter::area_of_study::AreaOfStudy;
+use formatter::diff::diff;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Compares two audit results for the same student and area
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// The earlier result, as JSON
+    before: String,
+    /// The later result, as JSON
+    after: String,
+    /// Print the changes as JSON
+    #[clap(long)]
+    json: bool,
+}
+
+fn load(path: &str) -> anyhow::Result<AreaOfStudy> {
+    let contents = std::fs::read_to_string(path)?;
+    let deserializer = &mut serde_json::Deserializer::from_str(&contents);
+
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|err| anyhow::anyhow!("{}: {} at {}", path, err, err.path()))
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    let before = load(&opts.before)?;
+    let after = load(&opts.after)?;
+
+    let changes = diff(&before, &after);
+
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&changes)?);
+    } else {
+        for change in &changes {
+            println!("{}", change);
+        }
+    }
+
+    Ok(())
+}
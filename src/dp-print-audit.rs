@@ -0,0 +1,95 @@
+use anyhow::{anyhow, bail};
+use clap::Clap;
+use formatter::messages::Catalog;
+use formatter::to_latex::LatexContext;
+use reports::database::connect;
+use reports::students::fetch_student;
+use std::path::PathBuf;
+use std::process::Command;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Renders a printable audit sheet for one student's area of study
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// The student to print
+    student_id: String,
+    /// Which area of study to print
+    area_code: String,
+    /// Where to write the .tex and .pdf files
+    #[clap(long, default_value = ".")]
+    output_dir: PathBuf,
+    /// The command used to turn the .tex file into a PDF; the file name is appended
+    #[clap(
+        long,
+        default_value = "pdflatex -interaction=nonstopmode -halt-on-error"
+    )]
+    pdf_command: String,
+    /// Print the LaTeX source instead of building a PDF
+    #[clap(long)]
+    tex_only: bool,
+    /// A JSON file of translated messages to use instead of English
+    #[clap(long)]
+    messages: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    let mut client = connect()?;
+
+    let (student, result) = fetch_student(&mut client, &opts.student_id, &opts.area_code)?
+        .ok_or_else(|| {
+            anyhow!(
+                "no active result for {} in {}",
+                opts.student_id,
+                opts.area_code
+            )
+        })?;
+
+    let messages = match &opts.messages {
+        Some(path) => Catalog::from_json(&std::fs::read_to_string(path)?)?,
+        None => Catalog::english(),
+    };
+
+    let tex = LatexContext {
+        result: &result,
+        student: &student,
+        messages: &messages,
+    }
+    .to_string();
+
+    if opts.tex_only {
+        print!("{}", tex);
+        return Ok(());
+    }
+
+    let filename = format!("{}-{}.tex", opts.student_id, opts.area_code);
+    std::fs::create_dir_all(&opts.output_dir)?;
+    std::fs::write(opts.output_dir.join(&filename), tex)?;
+
+    let mut parts = opts.pdf_command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow!("--pdf-command must not be empty"))?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&filename)
+        .current_dir(&opts.output_dir)
+        .status()?;
+
+    if !status.success() {
+        bail!("{} exited with {}", program, status);
+    }
+
+    println!(
+        "{}",
+        opts.output_dir
+            .join(filename)
+            .with_extension("pdf")
+            .display()
+    );
+
+    Ok(())
+}
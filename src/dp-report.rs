@@ -1,23 +1,56 @@
+use anyhow::bail;
 use clap::Clap;
-use reports::database::{collect_area_codes, connect, record_report};
-use reports::students::fetch_records;
-use reports::{run_report, ReportType};
+use formatter::to_record::RecordOptions;
+use reports::database::{
+    collect_area_codes, connect_with_default, expand_area_codes, record_report,
+    results_fingerprint, Client,
+};
+use reports::stats::{collect_stats, stats_as_csv};
+use reports::students::{
+    fetch_filtered_records, split_records, Monitor, RowError, SplitBy, StudentFilter, StudentRecord,
+};
+use reports::{
+    load_advisors, run_gap_report, run_gap_report_as_csv, run_grouped_report,
+    run_grouped_report_as_csv, run_interactive_report, run_mail_merge_as_csv, run_overview,
+    run_overview_as_csv, run_report, run_report_as_csv, run_report_as_xlsx, run_reports_as_xlsx,
+    set_jobs, stream_report, CsvLayout, CsvOptions, ReportType, StreamFormat,
+};
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
-const AUTHOR: &'static str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
 /// This doc string acts as a help message when the user runs '--help'
 /// as do all doc strings on fields
 #[derive(Clap)]
 #[clap(version = "1.0", author = AUTHOR)]
 struct Opts {
+    /// How many threads to parse results with; 0 uses one per CPU [default: 0]
+    #[clap(long)]
+    jobs: Option<usize>,
+    /// Reads defaults from this file instead of ./dp.toml
+    #[clap(long)]
+    config: Option<PathBuf>,
+    /// Logs how long each area spent querying, parsing, and formatting
+    #[clap(long)]
+    timings: bool,
+    /// Logs more detail; -v for each area's progress, -vv for each student and requirement
+    #[clap(short, long, parse(from_occurrences))]
+    verbose: u64,
+    /// Writes log messages to stderr as JSON lines
+    #[clap(long)]
+    log_json: bool,
     #[clap(subcommand)]
     action: SubCommand,
 }
 
 #[derive(Clap)]
 enum SubCommand {
-    Report(SubCmd),
+    Report(ReportSubCmd),
     Summarize(SubCmd),
     Batch(BatchSubCmd),
+    Stats(StatsSubCmd),
 }
 
 /// A subcommand for controlling testing
@@ -28,6 +61,141 @@ struct SubCmd {
     /// Stores the data into Postgres
     #[clap(long)]
     to_database: bool,
+    #[clap(flatten)]
+    columns: ColumnOpts,
+    #[clap(flatten)]
+    filters: FilterOpts,
+}
+
+/// A subcommand for controlling testing
+#[derive(Clap)]
+struct ReportSubCmd {
+    /// Which areas of study to look up; `*` matches any run of characters, as in `150*`
+    #[clap(required = true)]
+    area_codes: Vec<String>,
+    /// With several areas, writes each area's report into this directory as <area_code>.html [default: .]
+    #[clap(long)]
+    output_dir: Option<PathBuf>,
+    /// Writes the HTML report to this path instead of stdout
+    #[clap(long)]
+    output: Option<PathBuf>,
+    /// Prints one row per student with their overall status and progress, instead of every requirement
+    #[clap(long)]
+    summary: bool,
+    /// Lists each student's unmet requirements and what they still need, instead of every requirement
+    #[clap(long)]
+    gaps: bool,
+    /// With --as-csv, writes one row per student with their advisor and a summary of what's left,
+    /// for mail merges
+    #[clap(long)]
+    mail_merge: bool,
+    /// With --mail-merge, a CSV with "student id", "advisor", and "advisor email" columns, used
+    /// instead of the advisors in the student documents
+    #[clap(long)]
+    advisors: Option<PathBuf>,
+    /// Writes the HTML report as a standalone page with sortable columns and a student filter
+    #[clap(long)]
+    interactive: bool,
+    /// Keeps running, and regenerates the report whenever the areas' results change
+    #[clap(long)]
+    watch: bool,
+    /// With --watch, how many seconds to wait between checks for new results
+    #[clap(long, default_value = "30")]
+    watch_interval: u64,
+    /// Groups students by emphasis, catalog, or advisor within the one report, with a subtotal of
+    /// how many in each group have finished
+    #[clap(long)]
+    group_by: Option<SplitBy>,
+    /// Writes one file per emphasis, catalog, or advisor, named by adding the group to the output file name
+    #[clap(long)]
+    split_by: Option<SplitBy>,
+    /// Stores the data into Postgres
+    #[clap(long)]
+    to_database: bool,
+    /// Writes the report as a spreadsheet to the given path
+    #[clap(long)]
+    as_xlsx: Option<String>,
+    /// Writes the report as CSV to the given path
+    #[clap(long)]
+    as_csv: Option<String>,
+    /// With --as-csv, either "wide" (one row per student) or "long" (one row per student per requirement)
+    #[clap(long, default_value = "wide")]
+    csv_layout: CsvLayout,
+    /// With --as-csv, only keeps the requirement columns whose titles match this pattern, where
+    /// `*` matches anything; may be given more than once
+    #[clap(
+        long = "columns",
+        value_name = "pattern",
+        multiple_occurrences = true,
+        number_of_values = 1
+    )]
+    columns_to_keep: Vec<String>,
+    /// With --as-csv, drops the requirement columns whose titles match this pattern; may be given
+    /// more than once
+    #[clap(
+        long,
+        value_name = "pattern",
+        multiple_occurrences = true,
+        number_of_values = 1
+    )]
+    exclude_columns: Vec<String>,
+    #[clap(flatten)]
+    columns: ColumnOpts,
+    #[clap(flatten)]
+    filters: FilterOpts,
+}
+
+// Options for which columns a report has, and in what order. This is a plain comment because
+// clap would otherwise use it as the help text of every subcommand that flattens it in.
+#[derive(Clap)]
+struct ColumnOpts {
+    /// A file listing column titles, one per line, to pin to the front of the report
+    #[clap(long)]
+    column_order: Option<String>,
+    /// Adds a pass/fail/waived column for each requirement
+    #[clap(long)]
+    requirement_status: bool,
+    /// Adds a column with each requirement's fractional rank
+    #[clap(long)]
+    requirement_rank: bool,
+    /// Adds a column with the number of courses claimed by each requirement
+    #[clap(long)]
+    requirement_claims: bool,
+    /// Marks courses inserted by an exception, and cells that were waived
+    #[clap(long)]
+    mark_exceptions: bool,
+}
+
+// Options for which students to include; a plain comment for the same reason as ColumnOpts
+#[derive(Clap)]
+struct FilterOpts {
+    /// Only include students on this catalog
+    #[clap(long)]
+    catalog: Option<String>,
+    /// Only include students in this class year
+    #[clap(long)]
+    class_year: Option<String>,
+    /// Only include the student numbers listed in this file, one per line
+    #[clap(long)]
+    students_file: Option<String>,
+    /// Replaces student numbers and names with pseudonyms; the salt comes from --anonymize-salt or $DP_ANONYMIZE_SALT
+    #[clap(long)]
+    anonymize: bool,
+    /// The salt for --anonymize; reuse it to keep pseudonyms stable between reports
+    #[clap(long)]
+    anonymize_salt: Option<String>,
+}
+
+/// Aggregate statistics across every student in an area
+#[derive(Clap)]
+struct StatsSubCmd {
+    /// Which area of study to look up
+    area_code: String,
+    /// Prints the statistics as JSON instead of CSV
+    #[clap(long)]
+    as_json: bool,
+    #[clap(flatten)]
+    filters: FilterOpts,
 }
 
 /// A subcommand for controlling testing
@@ -38,26 +206,534 @@ struct BatchSubCmd {
     to_database: bool,
 }
 
-fn main() -> anyhow::Result<()> {
-    let opts: Opts = Opts::parse();
+/// Defaults read from dp.toml; anything given on the command line takes precedence
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    /// Used when $DATABASE_URL isn't set
+    database_url: Option<String>,
+    jobs: Option<usize>,
+    column_order: Option<String>,
+    output_dir: Option<PathBuf>,
+}
 
-    let mut client = connect()?;
+fn load_config(path: Option<&Path>) -> anyhow::Result<Config> {
+    let (path, required) = match path {
+        Some(path) => (path, true),
+        None => (Path::new("dp.toml"), false),
+    };
 
-    match opts.action {
+    if !required && !path.exists() {
+        return Ok(Config::default());
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    toml::from_str(&contents).map_err(|err| anyhow::anyhow!("{}: {}", path.display(), err))
+}
+
+fn apply_config(opts: &mut Opts, config: &Config) {
+    if opts.jobs.is_none() {
+        opts.jobs = config.jobs;
+    }
+
+    let columns = match &mut opts.action {
         SubCommand::Report(sopts) => {
-            let report_type = ReportType::Report;
-            let records = fetch_records(&mut client, &sopts.area_code)?;
-            let report = run_report(&records, &report_type)?;
+            if sopts.output_dir.is_none() {
+                sopts.output_dir = config.output_dir.clone();
+            }
+            Some(&mut sopts.columns)
+        }
+        SubCommand::Summarize(sopts) => Some(&mut sopts.columns),
+        SubCommand::Batch(_) | SubCommand::Stats(_) => None,
+    };
+
+    if let Some(columns) = columns {
+        if columns.column_order.is_none() {
+            columns.column_order = config.column_order.clone();
+        }
+    }
+}
+
+fn student_filter(filters: &FilterOpts) -> anyhow::Result<StudentFilter> {
+    let students = match &filters.students_file {
+        Some(path) => Some(
+            std::fs::read_to_string(path)?
+                .lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect(),
+        ),
+        None => None,
+    };
+
+    let anonymize_salt = if filters.anonymize {
+        match filters
+            .anonymize_salt
+            .clone()
+            .or_else(|| std::env::var("DP_ANONYMIZE_SALT").ok())
+        {
+            Some(salt) if !salt.is_empty() => Some(salt),
+            _ => bail!("--anonymize needs a salt from --anonymize-salt or $DP_ANONYMIZE_SALT"),
+        }
+    } else {
+        None
+    };
+
+    Ok(StudentFilter {
+        catalog: filters.catalog.clone(),
+        class_year: filters.class_year.clone(),
+        students,
+        anonymize_salt,
+    })
+}
+
+fn record_options(columns: &ColumnOpts) -> anyhow::Result<RecordOptions> {
+    let column_order = match &columns.column_order {
+        Some(path) => std::fs::read_to_string(path)?
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect(),
+        None => vec![],
+    };
+
+    Ok(RecordOptions {
+        column_order,
+        requirement_status: columns.requirement_status,
+        requirement_rank: columns.requirement_rank,
+        requirement_claims: columns.requirement_claims,
+        mark_exceptions: columns.mark_exceptions,
+        ..RecordOptions::default()
+    })
+}
+
+/// Fetches an area's records, reporting any rows that had to be skipped
+fn fetch(
+    client: &mut Client,
+    area_code: &str,
+    options: &RecordOptions,
+    filter: &StudentFilter,
+    skipped: &mut Vec<RowError>,
+    timings: bool,
+) -> anyhow::Result<Vec<StudentRecord>> {
+    let start = std::time::Instant::now();
+    let mut monitor = Monitor::with_progress(area_code);
+    let (records, errors) =
+        fetch_filtered_records(client, area_code, options, filter, &mut monitor)?;
+
+    log_fetch(area_code, start, &monitor, errors, skipped, timings);
+
+    Ok(records)
+}
+
+/// Writes the plain report for an area as its rows are read, instead of collecting them first
+fn stream(
+    client: &mut Client,
+    area_code: &str,
+    sopts: &ReportSubCmd,
+    format: &StreamFormat,
+    writer: impl Write,
+    skipped: &mut Vec<RowError>,
+    timings: bool,
+) -> anyhow::Result<()> {
+    let options = record_options(&sopts.columns)?;
+    let filter = student_filter(&sopts.filters)?;
+
+    let start = std::time::Instant::now();
+    let mut monitor = Monitor::with_progress(area_code);
+    let errors = stream_report(
+        client,
+        area_code,
+        &options,
+        &filter,
+        &mut monitor,
+        format,
+        BufWriter::new(writer),
+    )?;
+
+    log_fetch(area_code, start, &monitor, errors, skipped, timings);
+
+    Ok(())
+}
+
+fn log_fetch(
+    area_code: &str,
+    start: std::time::Instant,
+    monitor: &Monitor,
+    errors: Vec<RowError>,
+    skipped: &mut Vec<RowError>,
+    timings: bool,
+) {
+    if timings {
+        tracing::info!(
+            %area_code,
+            elapsed = ?start.elapsed(),
+            timings = %monitor.timings,
+            "fetched"
+        );
+    }
+
+    for err in &errors {
+        tracing::warn!(%area_code, stnum = %err.stnum, err = %err, "skipped a row");
+    }
+    skipped.extend(errors);
+}
+
+// "report.html" split by "2021-22" becomes "report-2021-22.html"
+fn split_path(path: &Path, group: &str) -> PathBuf {
+    let slug = group
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let name = match path.extension() {
+        Some(ext) => format!("{}-{}.{}", stem, slug, ext.to_string_lossy()),
+        None => format!("{}-{}", stem, slug),
+    };
+
+    path.with_file_name(name)
+}
+
+/// Renders the kind of HTML report the options ask for
+fn html_report(sopts: &ReportSubCmd, records: &[StudentRecord]) -> anyhow::Result<String> {
+    if sopts.summary {
+        run_overview(records)
+    } else if sopts.gaps {
+        run_gap_report(records)
+    } else if sopts.interactive {
+        run_interactive_report(records)
+    } else {
+        run_report(records, &ReportType::Report)
+    }
+}
+
+/// Renders the kind of CSV report the options ask for
+fn csv_report(sopts: &ReportSubCmd, records: &[StudentRecord]) -> anyhow::Result<String> {
+    if sopts.mail_merge {
+        let advisors = match &sopts.advisors {
+            Some(path) => load_advisors(path)?,
+            None => Default::default(),
+        };
+        run_mail_merge_as_csv(records, &advisors)
+    } else if sopts.summary {
+        run_overview_as_csv(records)
+    } else if sopts.gaps {
+        run_gap_report_as_csv(records)
+    } else {
+        run_report_as_csv(records, &csv_options(sopts))
+    }
+}
+
+fn csv_options(sopts: &ReportSubCmd) -> CsvOptions {
+    CsvOptions {
+        layout: sopts.csv_layout,
+        columns: sopts.columns_to_keep.clone(),
+        exclude_columns: sopts.exclude_columns.clone(),
+    }
+}
+
+// the plain report is the only one that can be written without collecting every record first
+fn stream_format(sopts: &ReportSubCmd) -> Option<StreamFormat> {
+    let collects = sopts.split_by.is_some()
+        || sopts.group_by.is_some()
+        || sopts.summary
+        || sopts.gaps
+        || sopts.interactive
+        || sopts.mail_merge
+        || sopts.to_database
+        || sopts.as_xlsx.is_some();
+
+    if collects {
+        None
+    } else if sopts.as_csv.is_some() {
+        Some(StreamFormat::Csv(csv_options(sopts)))
+    } else {
+        Some(StreamFormat::Html)
+    }
+}
+
+fn grouped_html_report(
+    sopts: &ReportSubCmd,
+    records: Vec<StudentRecord>,
+) -> anyhow::Result<String> {
+    match sopts.group_by {
+        Some(by) => run_grouped_report(&split_records(records, by), |records| {
+            html_report(sopts, records)
+        }),
+        None => html_report(sopts, &records),
+    }
+}
+
+fn run_single_report(
+    client: &mut Client,
+    area_code: &str,
+    sopts: &ReportSubCmd,
+    skipped: &mut Vec<RowError>,
+    timings: bool,
+) -> anyhow::Result<()> {
+    let report_type = ReportType::Report;
+    let options = record_options(&sopts.columns)?;
+    let filter = student_filter(&sopts.filters)?;
+
+    if let Some(format) = stream_format(sopts) {
+        let path = sopts
+            .as_csv
+            .as_ref()
+            .map(Path::new)
+            .or(sopts.output.as_deref());
+        return match path {
+            Some(path) => stream(
+                client,
+                area_code,
+                sopts,
+                &format,
+                File::create(path)?,
+                skipped,
+                timings,
+            ),
+            None => stream(
+                client,
+                area_code,
+                sopts,
+                &format,
+                std::io::stdout().lock(),
+                skipped,
+                timings,
+            ),
+        };
+    }
+
+    let records = fetch(client, area_code, &options, &filter, skipped, timings)?;
 
+    let groups = match sopts.split_by {
+        Some(by) => {
             if sopts.to_database {
-                record_report(&mut client, &report_type, &sopts.area_code, &report)?;
-            } else {
-                print!("{}", report);
+                bail!("--split-by can't be used with --to-database");
+            }
+            if sopts.as_xlsx.is_none() && sopts.as_csv.is_none() && sopts.output.is_none() {
+                bail!("--split-by needs a file to write to: --output, --as-csv, or --as-xlsx");
+            }
+
+            split_records(records, by).into_iter().collect()
+        }
+        None => vec![(String::new(), records)],
+    };
+
+    let target = |path: &Path, group: &str| {
+        if group.is_empty() {
+            path.to_path_buf()
+        } else {
+            split_path(path, group)
+        }
+    };
+
+    for (group, records) in groups {
+        if let Some(path) = &sopts.as_xlsx {
+            std::fs::write(
+                target(Path::new(path), &group),
+                run_report_as_xlsx(&records)?,
+            )?;
+            continue;
+        }
+
+        if let Some(path) = &sopts.as_csv {
+            let csv = match sopts.group_by {
+                Some(by) => run_grouped_report_as_csv(&split_records(records, by), |records| {
+                    csv_report(sopts, records)
+                })?,
+                None => csv_report(sopts, &records)?,
             };
+            std::fs::write(target(Path::new(path), &group), csv)?;
+            continue;
+        }
+
+        let report = grouped_html_report(sopts, records)?;
+
+        if sopts.to_database {
+            record_report(client, &report_type, area_code, &report)?;
+        } else if let Some(path) = &sopts.output {
+            std::fs::write(target(path, &group), report)?;
+        } else {
+            print!("{}", report);
+        };
+    }
+
+    Ok(())
+}
+
+/// Runs the report subcommand once, for every area matching the requested codes
+fn run_reports(
+    client: &mut Client,
+    sopts: &ReportSubCmd,
+    skipped: &mut Vec<RowError>,
+    timings: bool,
+) -> anyhow::Result<()> {
+    let area_codes = expand_area_codes(client, &sopts.area_codes)?;
+    if area_codes.is_empty() {
+        bail!("no areas matched {}", sopts.area_codes.join(", "));
+    }
+
+    if area_codes.len() == 1 {
+        run_single_report(client, &area_codes[0], sopts, skipped, timings)?;
+    } else if sopts.as_csv.is_some() {
+        bail!("--as-csv only supports a single area");
+    } else if sopts.output.is_some() || sopts.split_by.is_some() {
+        bail!("--output and --split-by only support a single area; see --output-dir");
+    } else {
+        let options = record_options(&sopts.columns)?;
+        let filter = student_filter(&sopts.filters)?;
+
+        if let Some(path) = &sopts.as_xlsx {
+            let mut areas = vec![];
+            for area_code in area_codes {
+                let records = fetch(client, &area_code, &options, &filter, skipped, timings)?;
+                areas.push((area_code, records));
+            }
+
+            std::fs::write(path, run_reports_as_xlsx(&areas)?)?;
+        } else {
+            let report_type = ReportType::Report;
+            let output_dir = sopts
+                .output_dir
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("."));
+            std::fs::create_dir_all(&output_dir)?;
+
+            for area_code in area_codes {
+                if let Some(format) = stream_format(sopts) {
+                    let path = output_dir.join(format!("{}.html", area_code));
+                    let file = File::create(&path)?;
+                    stream(client, &area_code, sopts, &format, file, skipped, timings)?;
+                    println!("{}", path.display());
+                    continue;
+                }
+
+                let records = fetch(client, &area_code, &options, &filter, skipped, timings)?;
+                let report = grouped_html_report(sopts, records)?;
+
+                if sopts.to_database {
+                    record_report(client, &report_type, &area_code, &report)?;
+                } else {
+                    let path = output_dir.join(format!("{}.html", area_code));
+                    std::fs::write(&path, report)?;
+                    println!("{}", path.display());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut opts: Opts = Opts::parse();
+
+    reports::logging::init(opts.verbose, opts.log_json);
+
+    let config = load_config(opts.config.as_deref())?;
+    apply_config(&mut opts, &config);
+
+    set_jobs(opts.jobs.unwrap_or(0))?;
+    let timings = opts.timings;
+
+    let mut client = connect_with_default(config.database_url.as_deref())?;
+
+    // rows that couldn't be parsed are left out of the reports, and make the run fail once it's done
+    let mut skipped = vec![];
+
+    match opts.action {
+        SubCommand::Report(sopts) => {
+            if sopts.summary && sopts.gaps {
+                bail!("--summary and --gaps can't be used together");
+            }
+            if (sopts.summary || sopts.gaps) && (sopts.as_xlsx.is_some() || sopts.to_database) {
+                bail!("--summary and --gaps can be written as HTML or CSV, but not as a spreadsheet or to the database");
+            }
+            if sopts.interactive
+                && (sopts.summary
+                    || sopts.gaps
+                    || sopts.to_database
+                    || sopts.as_csv.is_some()
+                    || sopts.as_xlsx.is_some())
+            {
+                bail!("--interactive only applies to the full HTML report, written to a file or stdout");
+            }
+            if sopts.mail_merge && (sopts.as_csv.is_none() || sopts.summary || sopts.gaps) {
+                bail!("--mail-merge is its own kind of report, and is only written with --as-csv");
+            }
+            if sopts.group_by.is_some()
+                && (sopts.interactive || sopts.to_database || sopts.as_xlsx.is_some())
+            {
+                bail!("--group-by works with HTML and CSV reports, but not --interactive, --as-xlsx, or --to-database");
+            }
+            if sopts.to_database && (sopts.as_xlsx.is_some() || sopts.as_csv.is_some()) {
+                bail!("--to-database can't be combined with --as-xlsx or --as-csv");
+            }
+            if sopts.advisors.is_some() && !sopts.mail_merge {
+                bail!("--advisors is only used by --mail-merge");
+            }
+            // the advisors file is keyed by student number, which --anonymize has already replaced
+            if sopts.advisors.is_some() && sopts.filters.anonymize {
+                bail!("--advisors can't be combined with --anonymize");
+            }
+
+            if sopts.watch && sopts.watch_interval == 0 {
+                bail!("--watch-interval must be at least 1 second");
+            }
+
+            if sopts.watch {
+                let interval = std::time::Duration::from_secs(sopts.watch_interval);
+                let mut last_seen = None;
+
+                // in watch mode, nothing ends the loop: a failed check or run is logged and then
+                // retried on the next tick, and skipped rows are reported on each run
+                loop {
+                    let fingerprint = expand_area_codes(&mut client, &sopts.area_codes)
+                        .and_then(|area_codes| results_fingerprint(&mut client, &area_codes));
+
+                    match fingerprint {
+                        Ok(fingerprint) if last_seen.as_ref() != Some(&fingerprint) => {
+                            match run_reports(&mut client, &sopts, &mut vec![], timings) {
+                                Ok(()) => {
+                                    tracing::info!(%fingerprint, "regenerated");
+                                    last_seen = Some(fingerprint);
+                                }
+                                Err(err) => {
+                                    tracing::warn!(err = %err, "could not regenerate; will retry")
+                                }
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(err) => {
+                            tracing::warn!(err = %err, "could not check for new results; will retry")
+                        }
+                    }
+
+                    std::thread::sleep(interval);
+                }
+            }
+
+            run_reports(&mut client, &sopts, &mut skipped, timings)?;
         }
         SubCommand::Summarize(sopts) => {
             let report_type = ReportType::Summary;
-            let records = fetch_records(&mut client, &sopts.area_code)?;
+            let options = record_options(&sopts.columns)?;
+            let filter = student_filter(&sopts.filters)?;
+            let records = fetch(
+                &mut client,
+                &sopts.area_code,
+                &options,
+                &filter,
+                &mut skipped,
+                timings,
+            )?;
             let report = run_report(&records, &report_type)?;
 
             if sopts.to_database {
@@ -66,38 +742,70 @@ fn main() -> anyhow::Result<()> {
                 print!("{}", report);
             };
         }
+        SubCommand::Stats(sopts) => {
+            let records = fetch(
+                &mut client,
+                &sopts.area_code,
+                &RecordOptions::default(),
+                &student_filter(&sopts.filters)?,
+                &mut skipped,
+                timings,
+            )?;
+            let stats = collect_stats(&records);
+
+            if sopts.as_json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else {
+                print!("{}", stats_as_csv(&stats)?);
+            }
+        }
         SubCommand::Batch(sopts) => {
-            use std::io::Write;
             use std::time::Instant;
 
             let area_codes = collect_area_codes(&mut client)?;
 
             for area_code in area_codes {
-                print!("{} | ", area_code);
-                std::io::stdout().flush()?;
                 let start = Instant::now();
-                let records = fetch_records(&mut client, &area_code)?;
-                print!("loaded {} in {:?}; ", records.len(), start.elapsed());
+                let records = fetch(
+                    &mut client,
+                    &area_code,
+                    &RecordOptions::default(),
+                    &StudentFilter::default(),
+                    &mut skipped,
+                    timings,
+                )?;
+                tracing::info!(
+                    %area_code,
+                    students = records.len(),
+                    elapsed = ?start.elapsed(),
+                    "loaded"
+                );
 
                 for report_type in &[ReportType::Report, ReportType::Summary] {
                     let start = Instant::now();
 
-                    match report_type {
-                        ReportType::Report => print!("report: "),
-                        ReportType::Summary => print!("summary: "),
-                    };
-                    let report = run_report(&records, &report_type)?;
+                    let report = run_report(&records, report_type)?;
                     if sopts.to_database {
-                        record_report(&mut client, &report_type, &area_code, &report)?;
+                        record_report(&mut client, report_type, &area_code, &report)?;
                     }
 
-                    print!("done in {:?}; ", start.elapsed());
-                    std::io::stdout().flush()?;
+                    tracing::info!(
+                        %area_code,
+                        report = match report_type {
+                            ReportType::Report => "report",
+                            ReportType::Summary => "summary",
+                        },
+                        elapsed = ?start.elapsed(),
+                        "rendered"
+                    );
                 }
-                println!();
             }
         }
     };
 
+    if !skipped.is_empty() {
+        bail!("skipped {} rows that could not be parsed", skipped.len());
+    }
+
     Ok(())
 }
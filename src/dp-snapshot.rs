@@ -0,0 +1,48 @@
+use anyhow::bail;
+use clap::Clap;
+use reports::snapshot::{check_corpus, Outcome};
+use std::path::PathBuf;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Checks the formatters' output for a corpus of stored results against golden files
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// A directory with one subdirectory per case, each holding student.json and result.json
+    corpus: PathBuf,
+    /// Rewrites the golden files from the current output instead of comparing against them
+    #[clap(long)]
+    update: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    let snapshots = check_corpus(&opts.corpus, opts.update)?;
+
+    let mut failed = 0;
+    for snapshot in &snapshots {
+        let name = format!("{}/{}", snapshot.case, snapshot.file_name);
+
+        match &snapshot.outcome {
+            Outcome::Matched => {}
+            Outcome::Updated => println!("updated {}", name),
+            Outcome::Missing => {
+                failed += 1;
+                println!("missing {}; run with --update to create it", name);
+            }
+            Outcome::Changed(diff) => {
+                failed += 1;
+                print!("{}", diff);
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of {} snapshots did not match", failed, snapshots.len());
+    }
+
+    eprintln!("{} snapshots ok", snapshots.len());
+
+    Ok(())
+}
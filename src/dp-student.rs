@@ -0,0 +1,123 @@
+use anyhow::anyhow;
+use clap::Clap;
+use formatter::messages::Catalog;
+use formatter::to_markdown::MarkdownContext;
+use formatter::to_prose::{ColorMode, ProseContext, ProseOptions, SymbolTheme};
+use reports::database::connect;
+use reports::students::fetch_student;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Prints one student's full audit for an area of study
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// The student to print
+    student_id: String,
+    /// Which area of study to print
+    area_code: String,
+    /// One of prose, markdown, or json
+    #[clap(long, default_value = "prose")]
+    format: Format,
+    /// Show each rule's path
+    #[clap(long)]
+    show_paths: bool,
+    /// Show each rule's rank
+    #[clap(long)]
+    show_ranks: bool,
+    /// List the unfinished requirements before the full audit
+    #[clap(long)]
+    summary: bool,
+    /// One of auto, always, or never
+    #[clap(long, default_value = "auto")]
+    color: ColorMode,
+    /// Wrap lines at this many columns
+    #[clap(long)]
+    width: Option<usize>,
+    /// Only print ASCII characters
+    #[clap(long)]
+    ascii: bool,
+    /// A JSON file of translated messages to use instead of English
+    #[clap(long)]
+    messages: Option<String>,
+}
+
+enum Format {
+    Prose,
+    Markdown,
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prose" => Ok(Format::Prose),
+            "markdown" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "expected one of prose, markdown, json; got {:?}",
+                s
+            )),
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    let mut client = connect()?;
+
+    let (student, result) = fetch_student(&mut client, &opts.student_id, &opts.area_code)?
+        .ok_or_else(|| {
+            anyhow!(
+                "no active result for {} in {}",
+                opts.student_id,
+                opts.area_code
+            )
+        })?;
+
+    let messages = match &opts.messages {
+        Some(path) => Catalog::from_json(&std::fs::read_to_string(path)?)?,
+        None => Catalog::english(),
+    };
+
+    match opts.format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&result)?),
+        Format::Markdown => print!(
+            "{}",
+            MarkdownContext {
+                result: &result,
+                student: &student,
+                messages: &messages,
+            }
+        ),
+        Format::Prose => {
+            let options = ProseOptions {
+                show_paths: opts.show_paths,
+                show_ranks: opts.show_ranks,
+                show_summary: opts.summary,
+                color: opts.color,
+                symbols: if opts.ascii {
+                    SymbolTheme::plain()
+                } else {
+                    SymbolTheme::unicode()
+                },
+                messages,
+                width: opts.width,
+                ascii: opts.ascii,
+            };
+
+            print!(
+                "{}",
+                ProseContext {
+                    result: &result,
+                    student: &student,
+                    options: &options,
+                }
+            );
+        }
+    }
+
+    Ok(())
+}
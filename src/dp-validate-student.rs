@@ -0,0 +1,105 @@
+use anyhow::bail;
+use clap::Clap;
+use formatter::validate::{validate_student, Issue, ValidateOptions};
+use reports::database::connect;
+use reports::local_audit::read_student;
+use reports::students::visit_students;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::path::PathBuf;
+
+const AUTHOR: &str = "Hawken MacKay Rives <degreepath@hawkrives.fastmail.fm>";
+/// Checks student documents for data problems that would throw off an audit
+#[derive(Clap)]
+#[clap(version = "1.0", author = AUTHOR)]
+struct Opts {
+    /// Checks every student with an active result in the database, along with their results
+    #[clap(long)]
+    database: bool,
+    /// Checks every .json student document in this directory
+    #[clap(long)]
+    dir: Option<PathBuf>,
+    /// Prints the issues as JSON
+    #[clap(long)]
+    json: bool,
+    /// Flags courses worth more than this many credits
+    #[clap(long)]
+    max_credits: Option<Decimal>,
+}
+
+#[derive(Serialize)]
+struct StudentIssues {
+    stnum: String,
+    issues: Vec<Issue>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let opts: Opts = Opts::parse();
+
+    reports::logging::init(0, false);
+
+    let options = ValidateOptions {
+        max_credits: opts.max_credits,
+    };
+
+    let mut found = vec![];
+    let mut check = |stnum: String, issues: Vec<Issue>| {
+        if !issues.is_empty() {
+            found.push(StudentIssues { stnum, issues });
+        }
+    };
+
+    match (&opts.dir, opts.database) {
+        (Some(dir), false) => {
+            let mut files = std::fs::read_dir(dir)?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            files.retain(|path| path.extension() == Some("json".as_ref()));
+            files.sort();
+
+            // a file that can't be read is reported against its path, and the rest are still checked
+            for path in files {
+                match read_student(&path) {
+                    Ok(student) => {
+                        let issues = validate_student(&student, &[], &options);
+                        check(student.stnum, issues);
+                    }
+                    Err(err) => check(
+                        path.display().to_string(),
+                        vec![Issue::Unreadable {
+                            message: format!("{:#}", err),
+                        }],
+                    ),
+                }
+            }
+        }
+        (None, true) => {
+            let mut client = connect()?;
+            let errors = visit_students(&mut client, |student, results| {
+                let issues = validate_student(&student, &results, &options);
+                check(student.stnum, issues);
+            })?;
+
+            for err in &errors {
+                tracing::warn!(stnum = %err.stnum, err = %err, "skipped a row");
+            }
+        }
+        _ => bail!("give either --database or --dir"),
+    }
+
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&found)?);
+    } else {
+        for student in &found {
+            for issue in &student.issues {
+                println!("{}: {}", student.stnum, issue);
+            }
+        }
+    }
+
+    if !found.is_empty() {
+        bail!("{} students have problems", found.len());
+    }
+
+    Ok(())
+}
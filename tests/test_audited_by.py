@@ -0,0 +1,50 @@
+from dp.area import AreaOfStudy
+from dp.data.student import Student
+from dp.constants import Constants
+from dp.status import ResultStatus
+import io
+import yaml
+
+c = Constants(matriculation_year=2000)
+
+
+def audit(spec: str):
+    area = AreaOfStudy.load(specification=yaml.load(stream=io.StringIO(spec), Loader=yaml.SafeLoader), c=c)
+    solution = next(area.solutions(student=Student.load(dict(courses=[])), exceptions=[]))
+    return solution.audit().result
+
+
+def test_audited_by_names_the_auditor():
+    result = audit("""
+        result: {requirement: Recital}
+        requirements:
+            Recital:
+                audited_by: music-office
+    """)
+
+    assert result.status() == ResultStatus.PendingApproval
+    assert result.to_dict()['audited_by'] == 'music-office'
+    assert result.to_dict()['is_audited'] is True
+
+
+def test_department_audited_maps_to_department():
+    result = audit("""
+        result: {requirement: Portfolio}
+        requirements:
+            Portfolio:
+                department_audited: true
+    """)
+
+    assert result.to_dict()['audited_by'] == 'department'
+
+
+def test_unaudited_requirement_has_no_auditor():
+    result = audit("""
+        result: {requirement: Intro}
+        requirements:
+            Intro:
+                result: {course: CSCI 121}
+    """)
+
+    assert result.to_dict()['audited_by'] is None
+    assert result.to_dict()['is_audited'] is False
@@ -0,0 +1,12 @@
+from dp.data.course_enums import CourseType
+import pathlib
+import re
+
+
+def test_validator_knows_every_course_type():
+    # dp-validate-student keeps its own copy of the course types, in Rust
+    source = pathlib.Path(__file__).parent.parent / 'formatter' / 'src' / 'validate.rs'
+    listing = re.search(r'const COURSE_TYPES: &\[&str\] = &\[(.*?)\];', source.read_text(), re.DOTALL)
+    assert listing is not None, 'could not find COURSE_TYPES in validate.rs'
+
+    assert set(re.findall(r'"(\w+)"', listing.group(1))) == {t.value for t in CourseType}
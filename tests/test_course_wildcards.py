@@ -0,0 +1,64 @@
+from dp.area import AreaOfStudy
+from dp.data.student import Student
+from dp.data.course import course_from_str, describe_course
+from dp.constants import Constants
+import io
+import yaml
+
+c = Constants(matriculation_year=2000)
+
+
+def load_area(spec: str) -> AreaOfStudy:
+    return AreaOfStudy.load(specification=yaml.load(stream=io.StringIO(spec), Loader=yaml.SafeLoader), c=c)
+
+
+def audit(area: AreaOfStudy, *courses: str):
+    student = Student.load(dict(courses=[course_from_str(s).to_dict() for s in courses]))
+    solution = next(area.solutions(student=student, exceptions=[]))
+    return solution.audit().result
+
+
+def test_wildcard_matches_any_course_at_that_level():
+    area = load_area("""
+        result:
+            course: CHEM 2XX
+    """)
+
+    result = audit(area, "CHEM 121", "CHEM 255")
+
+    assert result.is_ok() is True
+    assert result.claims()[0].course.course() == 'CHEM 255'
+
+
+def test_wildcard_does_not_match_other_levels_or_subjects():
+    area = load_area("""
+        result:
+            course: MATH 3**
+    """)
+
+    assert audit(area, "MATH 220", "CSCI 300", "MATH 3000").is_ok() is False
+
+
+def test_wildcard_courses_are_described_in_words():
+    assert describe_course("CHEM 2XX") == "any 200-level CHEM course"
+    assert describe_course("MATH 3**") == "any 300-level MATH course"
+    assert describe_course("CHEM XXX") == "any CHEM course"
+    assert describe_course("CSCI 121") == "CSCI 121"
+
+
+def test_wildcard_is_serialized_for_the_formatter():
+    area = load_area("""
+        result:
+            all:
+                - course: CHEM 2XX
+                - course: CHEM XXX
+                - course: CSCI 121
+    """)
+
+    items = audit(area).to_dict()['items']
+
+    assert [item['wildcard'] for item in items] == [
+        {"subject": "CHEM", "level": 200},
+        {"subject": "CHEM", "level": None},
+        None,
+    ]
@@ -0,0 +1,74 @@
+from dp.area import AreaOfStudy
+from dp.data.student import Student
+from dp.data.course import course_from_str
+from dp.constants import Constants
+from dp.equivalency import parse_equivalencies, load_equivalencies
+import io
+import yaml
+
+c = Constants(matriculation_year=2000)
+
+
+def test_parse_equivalencies_accepts_mapping_and_list():
+    assert parse_equivalencies({'CS 251': 'CSCI 251'}) == {'CS 251': 'CSCI 251'}
+    assert parse_equivalencies([{'from': 'CS 251', 'to': 'CSCI 251'}]) == {'CS 251': 'CSCI 251'}
+    assert parse_equivalencies(None) == {}
+
+
+def test_load_equivalencies_from_csv(tmp_path):
+    table = tmp_path / 'equivalencies.csv'
+    table.write_text('from,to\nCS 251,CSCI 251\n')
+
+    assert load_equivalencies(table) == {'CS 251': 'CSCI 251'}
+
+
+def test_equivalent_course_matches_course_rule():
+    area = AreaOfStudy.load(specification=yaml.load(stream=io.StringIO("""
+        result:
+            course: CSCI 251
+    """), Loader=yaml.SafeLoader), c=c)
+
+    transfer = course_from_str("CS 251", course_type="TR")
+    student = Student.load(dict(courses=[transfer.to_dict()]), equivalencies={'CS 251': 'CSCI 251'})
+
+    assert student.courses[0].course() == 'CSCI 251'
+    assert student.courses[0].substituted_for == 'CS 251'
+
+    solution = next(area.solutions(student=student, exceptions=[]))
+    result = solution.audit().result
+
+    assert result.is_ok() is True
+    assert result.claims()[0].to_dict()['substituted_for'] == 'CS 251'
+
+
+def test_unlisted_course_is_unchanged():
+    course = course_from_str("CSCI 121")
+    student = Student.load(dict(courses=[course.to_dict()]), equivalencies={'CS 251': 'CSCI 251'})
+
+    assert student.courses[0].course() == 'CSCI 121'
+    assert student.courses[0].substituted_for is None
+
+
+def test_equivalency_recomputes_level_and_chbi():
+    legacy = course_from_str("BIO 125")
+    student = Student.load(dict(courses=[legacy.to_dict()]), equivalencies={'BIO 125': 'CH/BI 227'})
+
+    course = student.courses[0]
+    assert course.course() == 'CH/BI 227'
+    assert course.level == 200
+    assert course.is_chbi_ == 227
+
+
+def test_equivalency_level_matches_level_predicate():
+    area = AreaOfStudy.load(specification=yaml.load(stream=io.StringIO("""
+        result:
+            from: courses
+            where: {$and: [{subject: {$eq: CSCI}}, {level: {$eq: 200}}]}
+            assert: {count(courses): {$gte: 1}}
+    """), Loader=yaml.SafeLoader), c=c)
+
+    transfer = course_from_str("CS 151", course_type="TR")
+    student = Student.load(dict(courses=[transfer.to_dict()]), equivalencies={'CS 151': 'CSCI 251'})
+
+    solution = next(area.solutions(student=student, exceptions=[]))
+    assert solution.audit().result.is_ok() is True
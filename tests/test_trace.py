@@ -0,0 +1,98 @@
+from dp.area import AreaOfStudy
+from dp.data.student import Student
+from dp.data.course import course_from_str
+from dp.constants import Constants
+from dp.trace import trace
+from dp.__main__ import main
+from dp.audit import audit as run_audit, ResultMsg, Arguments
+import io
+import json
+import yaml
+import pytest
+
+c = Constants(matriculation_year=2000)
+
+
+def audit(spec: str, courses):
+    area = AreaOfStudy.load(specification=yaml.load(stream=io.StringIO(spec), Loader=yaml.SafeLoader), c=c)
+    student = Student.load(dict(courses=[course.to_dict() for course in courses]))
+
+    [msg] = [msg for msg in run_audit(area=area, student=student, args=Arguments(trace=True)) if isinstance(msg, ResultMsg)]
+    return msg.result
+
+
+def decisions(entry):
+    return {candidate['course']: candidate.get('reason', candidate['decision']) for candidate in entry['candidates']}
+
+
+def test_trace_query_rule_reasons():
+    result = audit("""
+        result:
+            from: courses
+            where: {subject: {$eq: CHEM}}
+            limit:
+                - at_most: 1
+                  where: {level: {$eq: 100}}
+            assert: {count(courses): {$gte: 1}}
+    """, [
+        course_from_str("CHEM 121"),
+        course_from_str("CHEM 125"),
+        course_from_str("CSCI 121"),
+    ])
+
+    [entry] = trace(result)
+    found = decisions(entry)
+
+    # the limit only allows one of the two 100-level courses, and either one is as good as the other
+    assert entry['type'] == 'query'
+    assert sorted([found['CHEM 121'], found['CHEM 125']]) == ['counted', 'limit']
+    assert found['CSCI 121'] == 'filter-miss'
+
+
+def test_trace_course_rule_already_claimed():
+    result = audit("""
+        result:
+            all:
+                - requirement: First
+                - requirement: Second
+        requirements:
+            First:
+                result: {course: CHEM 121}
+            Second:
+                result: {course: CHEM 121}
+    """, [course_from_str("CHEM 121")])
+
+    first, second = trace(result)
+    clbid = result.context.transcript()[0].clbid
+
+    assert first['candidates'] == [{'clbid': clbid, 'course': 'CHEM 121', 'decision': 'counted'}]
+    assert second['candidates'] == [{
+        'clbid': clbid,
+        'course': 'CHEM 121',
+        'decision': 'rejected',
+        'reason': 'already-claimed',
+        'claimed_by': [list(first['path'])],
+    }]
+
+
+def test_trace_is_json():
+    result = audit("""
+        result:
+            course: CHEM 121
+    """, [course_from_str("CHEM 121", grade_code="C", grade_points="2.00")])
+
+    assert json.loads(json.dumps(trace(result))) == trace(result)
+
+
+def test_trace_is_only_recorded_when_asked():
+    area = AreaOfStudy.load(specification={'result': {'course': 'CHEM 121'}}, c=c)
+    student = Student.load(dict(courses=[course_from_str("CHEM 121").to_dict()]))
+
+    [msg] = [msg for msg in run_audit(area=area, student=student) if isinstance(msg, ResultMsg)]
+
+    assert msg.result.result.considered == tuple()
+
+
+def test_trace_requires_json():
+    with pytest.raises(SystemExit):
+        main(["--area", "area.yaml", "--student", "student.json", "--trace"])